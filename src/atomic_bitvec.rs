@@ -0,0 +1,379 @@
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+use crate::{
+    BitProto,
+    BitUtil,
+    IdxProxy,
+    LProtoBitVec,
+    RawBitVec,
+    Range,
+    ptr,
+};
+
+/// ## `AtomicBitVec`: "Atomic Bitwise Vector"  
+/// A fixed-length `BitVec` whose blocks are [`AtomicUsize`], allowing elements to be read and updated from many threads
+/// at once through a shared reference
+/// 
+/// Elements that fit entirely inside one block are updated with a single compare-and-swap loop on that block.
+/// 
+/// Elements that straddle two blocks (when `usize::BITS` is not a multiple of the bit width) are NOT lock-free: two
+/// blocks can't be swapped in one atomic operation, so every access to a straddling element, including `load()`, takes
+/// a one-bit spin lock for that block boundary, and a straddling element can never be observed half-written. A thread
+/// that finds the lock held spins (blocks) until the holder releases it, so a holder that is descheduled stalls every
+/// other thread waiting on the same boundary. Operations on non-straddling elements in the same blocks never take the
+/// lock, they only touch their own bits
+/// 
+/// ### Pros
+/// - Lock-free for every element that does not straddle a block boundary (always true for bit widths that divide `usize::BITS`)
+/// - 1-bit widths use `fetch_or()` / `fetch_and()` directly for `store()` and `swap()`
+/// - Same packed layout as the other variants, so it converts to and from [`LProtoBitVec`] by copying blocks
+/// 
+/// ### Cons
+/// - Fixed length (no push, pop, insert, remove, truncate, resize, split_off or extend_from_within), the other `Vec`
+///   operations are limited to `first()`, `last()`, `fill()`, `reverse()` and the rotations
+/// - Every operation on a straddling element spins on a lock, use a bit width that divides `usize::BITS` where blocking
+///   is not acceptable
+/// - A full copy of a [`BitProto`] stored in every [`AtomicBitVec`], plus one lock bit per block when elements can straddle blocks
+/// - Contention between threads updating different elements in the same block causes compare-and-swap retries
+pub struct AtomicBitVec {
+    pub(crate) proto: BitProto,
+    pub(crate) blocks: Box<[AtomicUsize]>,
+    pub(crate) locks: Box<[AtomicUsize]>,
+    pub(crate) len: usize,
+}
+
+impl AtomicBitVec {
+    /// Create a new [`AtomicBitVec`] with `len` elements all set to `0`
+    #[inline]
+    pub fn new(proto: BitProto, len: usize) -> Self {
+        let block_count = BitProto::calc_block_count_from_bitwise_count(proto, len);
+        let blocks = (0..block_count).map(|_| AtomicUsize::new(0)).collect();
+        Self {
+            proto,
+            blocks,
+            locks: Self::new_locks(proto, block_count),
+            len
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        self.proto
+    }
+
+    #[inline]
+    pub fn load(&self, idx: usize, order: Ordering) -> Result<usize, String> {
+        self.check_idx(idx, "load")?;
+        Ok(unsafe {self.load_unchecked(idx, order)})
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`
+    #[inline]
+    pub unsafe fn load_unchecked(&self, idx: usize, order: Ordering) -> usize {
+        let idx_proxy = BitProto::idx_proxy(self.proto, idx);
+        match idx_proxy.second_mask == 0 {
+            true => (self.blocks.get_unchecked(idx_proxy.real_idx).load(order) & idx_proxy.first_mask) >> idx_proxy.first_offset,
+            false => {
+                let _guard = self.lock_boundary(idx_proxy.real_idx);
+                self.load_straddled(idx_proxy, order)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn store(&self, idx: usize, val: usize, order: Ordering) -> Result<(), String> {
+        self.check_idx(idx, "store")?;
+        BitProto::check_value(self.proto, val)?;
+        unsafe {self.store_unchecked(idx, val, order)};
+        Ok(())
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`. Bits of `val` that do not fit in the bit width are discarded
+    #[inline]
+    pub unsafe fn store_unchecked(&self, idx: usize, val: usize, order: Ordering) {
+        self.swap_unchecked(idx, val, order);
+    }
+
+    #[inline]
+    pub fn swap(&self, idx: usize, val: usize, order: Ordering) -> Result<usize, String> {
+        self.check_idx(idx, "swap")?;
+        BitProto::check_value(self.proto, val)?;
+        Ok(unsafe {self.swap_unchecked(idx, val, order)})
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`. Bits of `val` that do not fit in the bit width are discarded
+    #[inline]
+    pub unsafe fn swap_unchecked(&self, idx: usize, val: usize, order: Ordering) -> usize {
+        let idx_proxy = BitProto::idx_proxy(self.proto, idx);
+        if self.proto.BITS == 1 {
+            let block = self.blocks.get_unchecked(idx_proxy.real_idx);
+            let old_block = match val & 1 {
+                0 => block.fetch_and(!idx_proxy.first_mask, order),
+                _ => block.fetch_or(idx_proxy.first_mask, order),
+            };
+            return (old_block & idx_proxy.first_mask) >> idx_proxy.first_offset;
+        }
+        match self.update_unchecked(idx_proxy, order, Self::load_order(order), |_| Some(val)) {
+            Ok(old_val) => old_val,
+            Err(old_val) => old_val,
+        }
+    }
+
+    #[inline]
+    pub fn compare_exchange(&self, idx: usize, current: usize, new: usize, success: Ordering, failure: Ordering) -> Result<Result<usize, usize>, String> {
+        self.check_idx(idx, "compare_exchange")?;
+        BitProto::check_value(self.proto, new)?;
+        Ok(unsafe {self.compare_exchange_unchecked(idx, current, new, success, failure)})
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`. Bits of `new` that do not fit in the bit width are discarded
+    #[inline]
+    pub unsafe fn compare_exchange_unchecked(&self, idx: usize, current: usize, new: usize, success: Ordering, failure: Ordering) -> Result<usize, usize> {
+        let idx_proxy = BitProto::idx_proxy(self.proto, idx);
+        self.update_unchecked(idx_proxy, success, failure, |val| match val == current {
+            true => Some(new),
+            false => None,
+        })
+    }
+
+    /// Repeatedly apply `f` to the current value of the element until the result is stored, or `f` returns `None`
+    /// 
+    /// Returns `Ok(Ok(previous_val))` if a new value was stored, `Ok(Err(current_val))` if `f` returned `None`,
+    /// and `Err(_)` if the index was out of bounds or `f` returned a value that cannot be represented in the bit width
+    #[inline]
+    pub fn fetch_update<F>(&self, idx: usize, set_order: Ordering, fetch_order: Ordering, mut f: F) -> Result<Result<usize, usize>, String>
+    where F: FnMut(usize) -> Option<usize> {
+        self.check_idx(idx, "fetch_update")?;
+        let mut bad_val = None;
+        let result = unsafe {self.fetch_update_unchecked(idx, set_order, fetch_order, |val| match f(val) {
+            Some(new_val) if new_val > self.proto.MASK => {
+                bad_val = Some(new_val);
+                None
+            },
+            new_val => new_val,
+        })};
+        if let Some(new_val) = bad_val {
+            BitProto::check_value(self.proto, new_val)?;
+        }
+        Ok(result)
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`. Bits of values returned by `f` that do not fit in the bit width are discarded
+    #[inline]
+    pub unsafe fn fetch_update_unchecked<F>(&self, idx: usize, set_order: Ordering, fetch_order: Ordering, f: F) -> Result<usize, usize>
+    where F: FnMut(usize) -> Option<usize> {
+        let idx_proxy = BitProto::idx_proxy(self.proto, idx);
+        self.update_unchecked(idx_proxy, set_order, fetch_order, f)
+    }
+
+    #[inline]
+    pub fn first(&self, order: Ordering) -> Result<usize, String> {
+        match self.len {
+            0 => Err(String::from("no elements in BitVec to get first")),
+            _ => Ok(unsafe {self.load_unchecked(0, order)})
+        }
+    }
+
+    #[inline]
+    pub fn last(&self, order: Ordering) -> Result<usize, String> {
+        match self.len {
+            0 => Err(String::from("no elements in BitVec to get last")),
+            _ => Ok(unsafe {self.load_unchecked(self.len - 1, order)})
+        }
+    }
+
+    /// Store `val` into every element, one element at a time (other threads may observe a partially filled `BitVec`)
+    #[inline]
+    pub fn fill(&self, val: usize, order: Ordering) -> Result<(), String> {
+        BitProto::check_value(self.proto, val)?;
+        for idx in 0..self.len {
+            unsafe {self.store_unchecked(idx, val, order)};
+        }
+        Ok(())
+    }
+
+    /// Reverse the order of the elements in place
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.reverse_range(0..self.len)
+    }
+
+    pub fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        if mid > self.len {
+            return Err(format!("index out of bounds for rotate_left: (mid) {} > {} (len)", mid, self.len));
+        }
+        self.reverse_range(0..mid);
+        self.reverse_range(mid..self.len);
+        self.reverse_range(0..self.len);
+        Ok(())
+    }
+
+    pub fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        if count > self.len {
+            return Err(format!("index out of bounds for rotate_right: (count) {} > {} (len)", count, self.len));
+        }
+        self.rotate_left(self.len - count)
+    }
+
+    #[inline]
+    pub fn into_lproto(self) -> LProtoBitVec {
+        let mut raw = RawBitVec::with_capacity(self.proto, self.len);
+        let mut block_idx = 0;
+        while block_idx < self.blocks.len() {
+            unsafe {ptr::write(raw.ptr.as_ptr().add(block_idx), self.blocks[block_idx].load(Ordering::Relaxed))};
+            block_idx += 1;
+        }
+        raw.len = self.len;
+        LProtoBitVec {
+            proto: self.proto,
+            vec: raw
+        }
+    }
+
+    /// Swap elements pairwise from both ends of `range`, which must be inside `0..self.len()` (`&mut self` means no
+    /// other thread can observe the intermediate states)
+    #[inline]
+    fn reverse_range(&mut self, range: Range<usize>) {
+        if range.len() < 2 {
+            return;
+        }
+        let mut low_idx = range.start;
+        let mut high_idx = range.end - 1;
+        while low_idx < high_idx {
+            unsafe {
+                let high_val = self.load_unchecked(high_idx, Ordering::Relaxed);
+                let low_val = self.swap_unchecked(low_idx, high_val, Ordering::Relaxed);
+                self.store_unchecked(high_idx, low_val, Ordering::Relaxed);
+            }
+            low_idx += 1;
+            high_idx -= 1;
+        }
+    }
+
+    #[inline(always)]
+    fn check_idx(&self, idx: usize, op_name: &str) -> Result<(), String> {
+        match idx < self.len {
+            true => Ok(()),
+            false => Err(format!("index out of bounds for {}: (idx) {} >= {} (len)", op_name, idx, self.len)),
+        }
+    }
+
+    #[inline(always)]
+    fn load_order(order: Ordering) -> Ordering {
+        match order {
+            Ordering::Release => Ordering::Relaxed,
+            Ordering::AcqRel => Ordering::Acquire,
+            order => order,
+        }
+    }
+
+    #[inline]
+    fn new_locks(proto: BitProto, block_count: usize) -> Box<[AtomicUsize]> {
+        match BitUtil::USIZE_BITS % proto.BITS {
+            0 => Box::new([]),
+            _ => (0..block_count.div_ceil(BitUtil::USIZE_BITS)).map(|_| AtomicUsize::new(0)).collect(),
+        }
+    }
+
+    #[inline]
+    fn lock_boundary(&self, boundary_idx: usize) -> BoundaryLockGuard<'_> {
+        let lock_block = &self.locks[boundary_idx / BitUtil::USIZE_BITS];
+        let lock_bit = 1 << (boundary_idx % BitUtil::USIZE_BITS);
+        while lock_block.fetch_or(lock_bit, Ordering::Acquire) & lock_bit != 0 {
+            while lock_block.load(Ordering::Relaxed) & lock_bit != 0 {
+                std::hint::spin_loop();
+            }
+        }
+        BoundaryLockGuard {
+            lock_block,
+            lock_bit
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn load_straddled(&self, idx_proxy: IdxProxy, order: Ordering) -> usize {
+        let first_bits = self.blocks.get_unchecked(idx_proxy.real_idx).load(order);
+        let second_bits = self.blocks.get_unchecked(idx_proxy.real_idx + 1).load(order);
+        ((first_bits & idx_proxy.first_mask) >> idx_proxy.first_offset) | ((second_bits & idx_proxy.second_mask) << idx_proxy.second_offset)
+    }
+
+    #[inline]
+    unsafe fn update_unchecked<F>(&self, idx_proxy: IdxProxy, set_order: Ordering, fetch_order: Ordering, mut f: F) -> Result<usize, usize>
+    where F: FnMut(usize) -> Option<usize> {
+        if idx_proxy.second_mask == 0 {
+            let block = self.blocks.get_unchecked(idx_proxy.real_idx);
+            let mut block_bits = block.load(fetch_order);
+            loop {
+                let val = (block_bits & idx_proxy.first_mask) >> idx_proxy.first_offset;
+                let new_val = match f(val) {
+                    Some(new_val) => new_val,
+                    None => return Err(val),
+                };
+                let new_block_bits = (block_bits & !idx_proxy.first_mask) | ((new_val << idx_proxy.first_offset) & idx_proxy.first_mask);
+                match block.compare_exchange_weak(block_bits, new_block_bits, set_order, fetch_order) {
+                    Ok(_) => return Ok(val),
+                    Err(actual_bits) => block_bits = actual_bits,
+                }
+            }
+        }
+        let _guard = self.lock_boundary(idx_proxy.real_idx);
+        let val = self.load_straddled(idx_proxy, fetch_order);
+        let new_val = match f(val) {
+            Some(new_val) => new_val,
+            None => return Err(val),
+        };
+        let new_first_bits = (new_val << idx_proxy.first_offset) & idx_proxy.first_mask;
+        let new_second_bits = (new_val >> idx_proxy.second_offset) & idx_proxy.second_mask;
+        let _ = self.blocks.get_unchecked(idx_proxy.real_idx).fetch_update(set_order, fetch_order, |block_bits| {
+            Some((block_bits & !idx_proxy.first_mask) | new_first_bits)
+        });
+        let _ = self.blocks.get_unchecked(idx_proxy.real_idx + 1).fetch_update(set_order, fetch_order, |block_bits| {
+            Some((block_bits & !idx_proxy.second_mask) | new_second_bits)
+        });
+        Ok(val)
+    }
+}
+
+impl From<LProtoBitVec> for AtomicBitVec {
+    #[inline]
+    fn from(bitvec: LProtoBitVec) -> Self {
+        let block_count = BitProto::calc_block_count_from_bitwise_count(bitvec.proto, bitvec.vec.len);
+        let blocks = (0..block_count).map(|block_idx| AtomicUsize::new(unsafe {ptr::read(bitvec.vec.ptr.as_ptr().add(block_idx))})).collect();
+        Self {
+            proto: bitvec.proto,
+            blocks,
+            locks: Self::new_locks(bitvec.proto, block_count),
+            len: bitvec.vec.len
+        }
+    }
+}
+
+struct BoundaryLockGuard<'vec> {
+    lock_block: &'vec AtomicUsize,
+    lock_bit: usize,
+}
+
+impl<'vec> Drop for BoundaryLockGuard<'vec> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.lock_block.fetch_and(!self.lock_bit, Ordering::Release);
+    }
+}
@@ -0,0 +1,508 @@
+use std::ops::RangeFrom;
+
+use crate::{
+    BitProto,
+    BitVecViewIter,
+    CProtoBitVec,
+    ConstGrowth,
+    LProtoBitVec,
+    SProtoBitVec,
+    TypedBitElem,
+    TypedBitVec,
+    TypedBitVecViewIter,
+    Range,
+};
+
+/// ## `BitVecLike`: "Bitwise Vector Like"
+/// The safe API shared by every `BitVec` variant that carries its own [`BitProto`], so that algorithms can be written
+/// once and used with [`CProtoBitVec`], [`SProtoBitVec`], [`LProtoBitVec`], [`TypedBitVec`] or [`ProtoBitVec`](crate::ProtoBitVec)
+///
+/// `Elem` is the type each element is read and written as: `usize` for the untyped variants, and `T::Base` for
+/// [`TypedBitVec<T>`]
+pub trait BitVecLike {
+    type Elem;
+    type Iter<'vec>: Iterator<Item = Self::Elem> + DoubleEndedIterator + ExactSizeIterator where Self: 'vec;
+
+    fn proto(&self) -> BitProto;
+    fn len(&self) -> usize;
+    fn cap(&self) -> usize;
+    fn free(&self) -> usize;
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn grow_exact_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String>;
+    fn grow_exact_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String>;
+    fn grow_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String>;
+    fn grow_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String>;
+    fn trim_excess_capacity(&mut self, extra_capacity_to_keep: usize) -> Result<(), String>;
+    fn clear(&mut self);
+    fn discard_from_end(&mut self, count: usize);
+
+    fn push(&mut self, val: Self::Elem) -> Result<(), String>;
+    fn pop(&mut self) -> Result<Self::Elem, String>;
+    fn insert(&mut self, idx: usize, val: Self::Elem) -> Result<(), String>;
+    fn remove(&mut self, idx: usize) -> Result<Self::Elem, String>;
+    fn swap(&mut self, idx_a: usize, idx_b: usize) -> Result<(), String>;
+    fn swap_pop(&mut self, idx: usize) -> Result<Self::Elem, String>;
+    fn get(&self, idx: usize) -> Result<Self::Elem, String>;
+    fn set(&mut self, idx: usize, val: Self::Elem) -> Result<(), String>;
+    fn replace(&mut self, idx: usize, val: Self::Elem) -> Result<Self::Elem, String>;
+
+    fn insert_iter<II>(&mut self, insert_idx: usize, source: II) -> Result<(), String>
+    where II: IntoIterator<Item = Self::Elem>, II::IntoIter: ExactSizeIterator;
+    fn append_iter<II>(&mut self, source: II) -> Result<(), String>
+    where II: IntoIterator<Item = Self::Elem>, II::IntoIter: ExactSizeIterator;
+    fn insert_bitvec(&mut self, insert_idx: usize, bitvec: Self) -> Result<(), String>
+    where Self: Sized;
+    fn append_bitvec(&mut self, bitvec: Self) -> Result<(), String>
+    where Self: Sized;
+    fn remove_range(&mut self, idx_range: Range<usize>) -> Result<Self, String>
+    where Self: Sized;
+    fn trim_range(&mut self, idx_range: RangeFrom<usize>) -> Result<Self, String>
+    where Self: Sized;
+
+    fn truncate(&mut self, new_len: usize);
+    fn reserve(&mut self, extra_elements: usize) -> Result<(), String>;
+    fn first(&self) -> Result<Self::Elem, String>;
+    fn last(&self) -> Result<Self::Elem, String>;
+    fn resize(&mut self, new_len: usize, val: Self::Elem) -> Result<(), String>;
+    fn fill(&mut self, val: Self::Elem) -> Result<(), String>;
+    fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String>;
+    fn split_off(&mut self, at: usize) -> Result<Self, String>
+    where Self: Sized;
+    fn reverse(&mut self);
+    fn rotate_left(&mut self, mid: usize) -> Result<(), String>;
+    fn rotate_right(&mut self, count: usize) -> Result<(), String>;
+    fn fill_range(&mut self, range: Range<usize>, val: Self::Elem) -> Result<(), String>;
+    fn repeat(&self, count: usize) -> Result<Self, String>
+    where Self: Sized;
+
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+macro_rules! impl_bitvec_like_untyped {
+    ($([$($GEN:tt)*] $TYPE:ty),+) => {$(
+        impl<$($GEN)*> BitVecLike for $TYPE {
+            type Elem = usize;
+            type Iter<'vec> = BitVecViewIter<'vec> where Self: 'vec;
+
+            #[inline(always)]
+            fn proto(&self) -> BitProto {
+                self.proto()
+            }
+
+            #[inline(always)]
+            fn len(&self) -> usize {
+                self.len()
+            }
+
+            #[inline(always)]
+            fn cap(&self) -> usize {
+                self.cap()
+            }
+
+            #[inline(always)]
+            fn free(&self) -> usize {
+                self.free()
+            }
+
+            #[inline(always)]
+            fn grow_exact_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
+                self.grow_exact_for_additional_elements_if_needed(extra_elements)
+            }
+
+            #[inline(always)]
+            fn grow_exact_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+                self.grow_exact_for_total_elements_if_needed(total_elements)
+            }
+
+            #[inline(always)]
+            fn grow_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
+                self.grow_for_additional_elements_if_needed(extra_elements)
+            }
+
+            #[inline(always)]
+            fn grow_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+                self.grow_for_total_elements_if_needed(total_elements)
+            }
+
+            #[inline(always)]
+            fn trim_excess_capacity(&mut self, extra_capacity_to_keep: usize) -> Result<(), String> {
+                self.trim_excess_capacity(extra_capacity_to_keep)
+            }
+
+            #[inline(always)]
+            fn clear(&mut self) {
+                self.clear()
+            }
+
+            #[inline(always)]
+            fn discard_from_end(&mut self, count: usize) {
+                self.discard_from_end(count)
+            }
+
+            #[inline(always)]
+            fn push(&mut self, val: usize) -> Result<(), String> {
+                self.push(val)
+            }
+
+            #[inline(always)]
+            fn pop(&mut self) -> Result<usize, String> {
+                self.pop()
+            }
+
+            #[inline(always)]
+            fn insert(&mut self, idx: usize, val: usize) -> Result<(), String> {
+                self.insert(idx, val)
+            }
+
+            #[inline(always)]
+            fn remove(&mut self, idx: usize) -> Result<usize, String> {
+                self.remove(idx)
+            }
+
+            #[inline(always)]
+            fn swap(&mut self, idx_a: usize, idx_b: usize) -> Result<(), String> {
+                self.swap(idx_a, idx_b)
+            }
+
+            #[inline(always)]
+            fn swap_pop(&mut self, idx: usize) -> Result<usize, String> {
+                self.swap_pop(idx)
+            }
+
+            #[inline(always)]
+            fn get(&self, idx: usize) -> Result<usize, String> {
+                self.get(idx)
+            }
+
+            #[inline(always)]
+            fn set(&mut self, idx: usize, val: usize) -> Result<(), String> {
+                self.set(idx, val)
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, idx: usize, val: usize) -> Result<usize, String> {
+                self.replace(idx, val)
+            }
+
+            #[inline(always)]
+            fn insert_iter<II>(&mut self, insert_idx: usize, source: II) -> Result<(), String>
+            where II: IntoIterator<Item = usize>, II::IntoIter: ExactSizeIterator {
+                self.insert_iter(insert_idx, source)
+            }
+
+            #[inline(always)]
+            fn append_iter<II>(&mut self, source: II) -> Result<(), String>
+            where II: IntoIterator<Item = usize>, II::IntoIter: ExactSizeIterator {
+                self.append_iter(source)
+            }
+
+            #[inline(always)]
+            fn insert_bitvec(&mut self, insert_idx: usize, bitvec: Self) -> Result<(), String> {
+                self.insert_bitvec(insert_idx, bitvec)
+            }
+
+            #[inline(always)]
+            fn append_bitvec(&mut self, bitvec: Self) -> Result<(), String> {
+                self.append_bitvec(bitvec)
+            }
+
+            #[inline(always)]
+            fn remove_range(&mut self, idx_range: Range<usize>) -> Result<Self, String> {
+                self.remove_range(idx_range)
+            }
+
+            #[inline(always)]
+            fn trim_range(&mut self, idx_range: RangeFrom<usize>) -> Result<Self, String> {
+                self.trim_range(idx_range)
+            }
+
+            #[inline(always)]
+            fn truncate(&mut self, new_len: usize) {
+                self.truncate(new_len)
+            }
+
+            #[inline(always)]
+            fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+                self.reserve(extra_elements)
+            }
+
+            #[inline(always)]
+            fn first(&self) -> Result<usize, String> {
+                self.first()
+            }
+
+            #[inline(always)]
+            fn last(&self) -> Result<usize, String> {
+                self.last()
+            }
+
+            #[inline(always)]
+            fn resize(&mut self, new_len: usize, val: usize) -> Result<(), String> {
+                self.resize(new_len, val)
+            }
+
+            #[inline(always)]
+            fn fill(&mut self, val: usize) -> Result<(), String> {
+                self.fill(val)
+            }
+
+            #[inline(always)]
+            fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+                self.extend_from_within(src_range)
+            }
+
+            #[inline(always)]
+            fn split_off(&mut self, at: usize) -> Result<Self, String> {
+                self.split_off(at)
+            }
+
+            #[inline(always)]
+            fn reverse(&mut self) {
+                self.reverse()
+            }
+
+            #[inline(always)]
+            fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+                self.rotate_left(mid)
+            }
+
+            #[inline(always)]
+            fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+                self.rotate_right(count)
+            }
+
+            #[inline(always)]
+            fn fill_range(&mut self, range: Range<usize>, val: usize) -> Result<(), String> {
+                self.fill_range(range, val)
+            }
+
+            #[inline(always)]
+            fn repeat(&self, count: usize) -> Result<Self, String> {
+                self.repeat(count)
+            }
+
+            #[inline(always)]
+            fn iter(&self) -> BitVecViewIter<'_> {
+                self.iter()
+            }
+        }
+    )+};
+}
+
+impl_bitvec_like_untyped!(
+    [const BIT_WIDTH: usize, G: ConstGrowth] CProtoBitVec<BIT_WIDTH, G>,
+    [] SProtoBitVec,
+    [] LProtoBitVec
+);
+
+impl<T: TypedBitElem> BitVecLike for TypedBitVec<T> {
+    type Elem = T::Base;
+    type Iter<'vec> = TypedBitVecViewIter<'vec, T> where Self: 'vec;
+
+    #[inline(always)]
+    fn proto(&self) -> BitProto {
+        T::PROTO
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn cap(&self) -> usize {
+        self.cap()
+    }
+
+    #[inline(always)]
+    fn free(&self) -> usize {
+        self.free()
+    }
+
+    #[inline(always)]
+    fn grow_exact_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
+        self.grow_exact_for_additional_elements_if_needed(extra_elements)
+    }
+
+    #[inline(always)]
+    fn grow_exact_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+        self.grow_exact_for_total_elements_if_needed(total_elements)
+    }
+
+    #[inline(always)]
+    fn grow_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
+        self.grow_for_additional_elements_if_needed(extra_elements)
+    }
+
+    #[inline(always)]
+    fn grow_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+        self.grow_for_total_elements_if_needed(total_elements)
+    }
+
+    #[inline(always)]
+    fn trim_excess_capacity(&mut self, extra_capacity_to_keep: usize) -> Result<(), String> {
+        self.trim_excess_capacity(extra_capacity_to_keep)
+    }
+
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.clear()
+    }
+
+    #[inline(always)]
+    fn discard_from_end(&mut self, count: usize) {
+        self.discard_from_end(count)
+    }
+
+    #[inline(always)]
+    fn push(&mut self, val: T::Base) -> Result<(), String> {
+        self.push(val)
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Result<T::Base, String> {
+        self.pop()
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, idx: usize, val: T::Base) -> Result<(), String> {
+        self.insert(idx, val)
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, idx: usize) -> Result<T::Base, String> {
+        self.remove(idx)
+    }
+
+    #[inline(always)]
+    fn swap(&mut self, idx_a: usize, idx_b: usize) -> Result<(), String> {
+        self.swap(idx_a, idx_b)
+    }
+
+    #[inline(always)]
+    fn swap_pop(&mut self, idx: usize) -> Result<T::Base, String> {
+        self.swap_pop(idx)
+    }
+
+    #[inline(always)]
+    fn get(&self, idx: usize) -> Result<T::Base, String> {
+        self.get(idx)
+    }
+
+    #[inline(always)]
+    fn set(&mut self, idx: usize, val: T::Base) -> Result<(), String> {
+        self.set(idx, val)
+    }
+
+    #[inline(always)]
+    fn replace(&mut self, idx: usize, val: T::Base) -> Result<T::Base, String> {
+        self.replace(idx, val)
+    }
+
+    #[inline(always)]
+    fn insert_iter<II>(&mut self, insert_idx: usize, source: II) -> Result<(), String>
+    where II: IntoIterator<Item = T::Base>, II::IntoIter: ExactSizeIterator {
+        self.insert_vals_checked(insert_idx, source.into_iter())
+    }
+
+    #[inline(always)]
+    fn append_iter<II>(&mut self, source: II) -> Result<(), String>
+    where II: IntoIterator<Item = T::Base>, II::IntoIter: ExactSizeIterator {
+        self.append_vals_checked(source.into_iter())
+    }
+
+    #[inline(always)]
+    fn insert_bitvec(&mut self, insert_idx: usize, bitvec: Self) -> Result<(), String> {
+        self.insert_bitvec(insert_idx, bitvec)
+    }
+
+    #[inline(always)]
+    fn append_bitvec(&mut self, bitvec: Self) -> Result<(), String> {
+        self.append_bitvec(bitvec)
+    }
+
+    #[inline(always)]
+    fn remove_range(&mut self, idx_range: Range<usize>) -> Result<Self, String> {
+        self.remove_range(idx_range)
+    }
+
+    #[inline(always)]
+    fn trim_range(&mut self, idx_range: RangeFrom<usize>) -> Result<Self, String> {
+        self.trim_range(idx_range)
+    }
+
+    #[inline(always)]
+    fn truncate(&mut self, new_len: usize) {
+        self.truncate(new_len)
+    }
+
+    #[inline(always)]
+    fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+        self.reserve(extra_elements)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Result<T::Base, String> {
+        self.first()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Result<T::Base, String> {
+        self.last()
+    }
+
+    #[inline(always)]
+    fn resize(&mut self, new_len: usize, val: T::Base) -> Result<(), String> {
+        self.resize(new_len, val)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, val: T::Base) -> Result<(), String> {
+        self.fill(val)
+    }
+
+    #[inline(always)]
+    fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+        self.extend_from_within(src_range)
+    }
+
+    #[inline(always)]
+    fn split_off(&mut self, at: usize) -> Result<Self, String> {
+        self.split_off(at)
+    }
+
+    #[inline(always)]
+    fn reverse(&mut self) {
+        self.reverse()
+    }
+
+    #[inline(always)]
+    fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        self.rotate_left(mid)
+    }
+
+    #[inline(always)]
+    fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        self.rotate_right(count)
+    }
+
+    #[inline(always)]
+    fn fill_range(&mut self, range: Range<usize>, val: T::Base) -> Result<(), String> {
+        self.fill_range(range, val)
+    }
+
+    #[inline(always)]
+    fn repeat(&self, count: usize) -> Result<Self, String> {
+        self.repeat(count)
+    }
+
+    #[inline(always)]
+    fn iter(&self) -> TypedBitVecViewIter<'_, T> {
+        self.iter()
+    }
+}
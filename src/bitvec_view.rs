@@ -0,0 +1,146 @@
+use crate::{
+    BitProto,
+    BitVecViewIter,
+    NonNull,
+    PhantomData,
+    Range,
+    PackedUint,
+    check_unpack_buffer,
+    unpack_blocks,
+};
+
+/// ## `BitVecView`: "Bitwise Vector View"  
+/// A borrowed, read-only window into a range of elements stored in packed `usize` blocks, along with the
+/// [`BitProto`] needed to read them
+/// 
+/// A [`BitVecView`] does not own the memory it reads from, it only borrows it for the lifetime `'view`. It can be
+/// narrowed further with [`BitVecView::slice()`] without copying any data
+/// 
+/// ### Pros
+/// - Reads elements in-place, no copying into a new allocation
+/// - Works over any packed block storage (owned vectors, memory-mapped files, etc.)
+/// 
+/// ### Cons
+/// - A full copy of a [`BitProto`] stored in every [`BitVecView`]
+/// - Read-only
+pub struct BitVecView<'view> {
+    pub(crate) proto: BitProto,
+    pub(crate) ptr: NonNull<usize>,
+    pub(crate) start: usize,
+    pub(crate) len: usize,
+    pub(crate) _borrow: PhantomData<&'view [usize]>,
+}
+
+impl<'view> BitVecView<'view> {
+    /// Create a view over the first `len` elements stored in the blocks at `ptr`
+    /// 
+    /// ## Safety
+    /// `ptr` must point to enough initialized blocks to hold `len` elements of `proto`, and that memory must
+    /// not be written to for the lifetime `'view`
+    #[inline(always)]
+    pub unsafe fn from_raw_parts(proto: BitProto, ptr: NonNull<usize>, len: usize) -> Self {
+        Self {
+            proto,
+            ptr,
+            start: 0,
+            len,
+            _borrow: PhantomData
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        self.proto
+    }
+
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Result<usize, String> {
+        match idx < self.len {
+            true => Ok(unsafe {self.get_unchecked(idx)}),
+            false => Err(format!("index out of bounds for get: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> usize {
+        let idx_proxy = BitProto::idx_proxy(self.proto, self.start + idx);
+        idx_proxy.read_val(self.ptr.as_ptr())
+    }
+
+    /// Copy every element in `idx_range` into `out`, which must be exactly `idx_range.len()` long, decoding whole
+    /// blocks at a time with a kernel specialized for the element width
+    #[inline]
+    pub fn unpack_into<U: PackedUint>(&self, idx_range: Range<usize>, out: &mut [U]) -> Result<(), String> {
+        if idx_range.start > idx_range.end || idx_range.end > self.len {
+            return Err(format!("index out of bounds for unpack_into: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", idx_range.start, idx_range.end, idx_range.end, self.len));
+        }
+        check_unpack_buffer::<U>(self.proto, idx_range.len(), out.len())?;
+        unsafe {unpack_blocks(self.proto, self.ptr.as_ptr(), self.start + idx_range.start, out)};
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn slice(&self, idx_range: Range<usize>) -> Result<BitVecView<'view>, String> {
+        match idx_range.start > idx_range.end || idx_range.end > self.len {
+            true => Err(format!("index out of bounds for slice: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", idx_range.start, idx_range.end, idx_range.end, self.len)),
+            false => Ok(Self {
+                proto: self.proto,
+                ptr: self.ptr,
+                start: self.start + idx_range.start,
+                len: idx_range.len(),
+                _borrow: PhantomData
+            })
+        }
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> BitVecViewIter<'view> {
+        BitVecViewIter {
+            proto: self.proto,
+            ptr: self.ptr,
+            start: self.start,
+            end_excluded: self.start + self.len,
+            _borrow: PhantomData
+        }
+    }
+}
+
+// SAFETY: a BitVecView only ever reads from memory that is borrowed immutably for `'view`, exactly like a `&'view [usize]`
+unsafe impl<'view> Send for BitVecView<'view> {}
+unsafe impl<'view> Sync for BitVecView<'view> {}
+
+impl<'view> Clone for BitVecView<'view> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            proto: self.proto,
+            ptr: self.ptr,
+            start: self.start,
+            len: self.len,
+            _borrow: PhantomData
+        }
+    }
+}
+
+impl<'view> IntoIterator for BitVecView<'view> {
+    type Item = usize;
+
+    type IntoIter = BitVecViewIter<'view>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
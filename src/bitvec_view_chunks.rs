@@ -0,0 +1,540 @@
+use std::iter::Map;
+
+use crate::{
+    BitProto,
+    BitUtil,
+    BitVecView,
+    TypedBitElem,
+    TypedBitVecView,
+    NonNull,
+    PhantomData,
+    Range,
+    ptr,
+};
+
+impl<'view> BitVecView<'view> {
+    /// Split the view into sub-views of `chunk_len` elements starting from the front, the last chunk is shorter
+    /// if `chunk_len` does not divide `len()`
+    #[inline(always)]
+    pub fn chunks(&self, chunk_len: usize) -> Result<BitVecViewChunks<'view>, String> {
+        Self::check_chunk_len("chunks", chunk_len)?;
+        Ok(BitVecViewChunks { view: self.clone(), chunk_len })
+    }
+
+    /// Split the view into sub-views of exactly `chunk_len` elements starting from the front, any leftover elements
+    /// are skipped and available from `remainder()`
+    #[inline(always)]
+    pub fn chunks_exact(&self, chunk_len: usize) -> Result<BitVecViewChunksExact<'view>, String> {
+        Self::check_chunk_len("chunks_exact", chunk_len)?;
+        let exact_len = self.len - (self.len % chunk_len);
+        Ok(BitVecViewChunksExact {
+            view: self.sub_view(0, exact_len),
+            remainder: self.sub_view(exact_len, self.len - exact_len),
+            chunk_len
+        })
+    }
+
+    /// Split the view into sub-views of `chunk_len` elements starting from the back, the last chunk is shorter
+    /// if `chunk_len` does not divide `len()`
+    #[inline(always)]
+    pub fn rchunks(&self, chunk_len: usize) -> Result<BitVecViewRChunks<'view>, String> {
+        Self::check_chunk_len("rchunks", chunk_len)?;
+        Ok(BitVecViewRChunks { view: self.clone(), chunk_len })
+    }
+
+    /// Every overlapping sub-view of `window_len` elements, from the front
+    #[inline(always)]
+    pub fn windows(&self, window_len: usize) -> Result<BitVecViewWindows<'view>, String> {
+        Self::check_chunk_len("windows", window_len)?;
+        Ok(BitVecViewWindows { view: self.clone(), window_len })
+    }
+
+    /// Like `chunks_exact()`, but every chunk is decoded into an array of `N` elements
+    ///
+    /// `N == 0` fails to compile
+    #[inline(always)]
+    pub fn array_chunks<const N: usize>(&self) -> BitVecViewArrayChunks<'view, N> {
+        let exact_len = self.len - (self.len % BitVecViewArrayChunks::<N>::N);
+        BitVecViewArrayChunks {
+            view: self.sub_view(0, exact_len),
+            remainder: self.sub_view(exact_len, self.len - exact_len),
+        }
+    }
+
+    /// Like `windows()`, but every window is decoded into an array of `N` elements
+    ///
+    /// `N == 0` fails to compile
+    #[inline(always)]
+    pub fn array_windows<const N: usize>(&self) -> BitVecViewArrayWindows<'view, N> {
+        let _ = BitVecViewArrayWindows::<N>::N;
+        BitVecViewArrayWindows { view: self.clone() }
+    }
+
+    /// Every `usize` block the view touches, in order, paired with the range of view indexes whose elements
+    /// lie entirely within that block
+    ///
+    /// The range is empty for a block that only holds parts of elements, and the block is returned unmasked: any
+    /// bits outside the range belong to elements that straddle the block edges, to elements outside the view, or
+    /// to unused capacity
+    #[inline(always)]
+    pub fn block_chunks(&self) -> BitVecViewBlockChunks<'view> {
+        let (start_block, end_block) = match self.len {
+            0 => (0, 0),
+            _ => (
+                BitProto::idx_proxy(self.proto, self.start).real_idx,
+                BitProto::calc_block_count_from_bitwise_count(self.proto, self.start + self.len)
+            )
+        };
+        BitVecViewBlockChunks {
+            proto: self.proto,
+            ptr: self.ptr,
+            view_start: self.start,
+            view_end_excluded: self.start + self.len,
+            start_block,
+            end_block,
+            _borrow: PhantomData
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_view(&self, start: usize, len: usize) -> BitVecView<'view> {
+        BitVecView {
+            proto: self.proto,
+            ptr: self.ptr,
+            start: self.start + start,
+            len,
+            _borrow: PhantomData
+        }
+    }
+
+    #[inline(always)]
+    fn check_chunk_len(method: &str, chunk_len: usize) -> Result<(), String> {
+        match chunk_len {
+            0 => Err(format!("chunk length for {} cannot be 0", method)),
+            _ => Ok(())
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn read_array<const N: usize>(&self, start: usize) -> [usize; N] {
+        let mut vals = [0usize; N];
+        for (offset, val) in vals.iter_mut().enumerate() {
+            *val = self.get_unchecked(start + offset);
+        }
+        vals
+    }
+}
+
+pub struct BitVecViewChunks<'view> {
+    pub(crate) view: BitVecView<'view>,
+    pub(crate) chunk_len: usize,
+}
+
+impl<'view> Iterator for BitVecViewChunks<'view> {
+    type Item = BitVecView<'view>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let take = self.chunk_len.min(len);
+                let chunk = self.view.sub_view(0, take);
+                self.view = self.view.sub_view(take, len - take);
+                Some(chunk)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.view.len.div_ceil(self.chunk_len);
+        (len, Some(len))
+    }
+}
+
+impl<'view> DoubleEndedIterator for BitVecViewChunks<'view> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let take = match len % self.chunk_len {
+                    0 => self.chunk_len,
+                    short => short
+                };
+                let chunk = self.view.sub_view(len - take, take);
+                self.view = self.view.sub_view(0, len - take);
+                Some(chunk)
+            }
+        }
+    }
+}
+
+impl<'view> ExactSizeIterator for BitVecViewChunks<'view> {}
+
+pub struct BitVecViewChunksExact<'view> {
+    pub(crate) view: BitVecView<'view>,
+    pub(crate) remainder: BitVecView<'view>,
+    pub(crate) chunk_len: usize,
+}
+
+impl<'view> BitVecViewChunksExact<'view> {
+    /// The elements at the end that don't fill a whole chunk
+    #[inline(always)]
+    pub fn remainder(&self) -> BitVecView<'view> {
+        self.remainder.clone()
+    }
+}
+
+impl<'view> Iterator for BitVecViewChunksExact<'view> {
+    type Item = BitVecView<'view>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let chunk = self.view.sub_view(0, self.chunk_len);
+                self.view = self.view.sub_view(self.chunk_len, len - self.chunk_len);
+                Some(chunk)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.view.len / self.chunk_len;
+        (len, Some(len))
+    }
+}
+
+impl<'view> DoubleEndedIterator for BitVecViewChunksExact<'view> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let chunk = self.view.sub_view(len - self.chunk_len, self.chunk_len);
+                self.view = self.view.sub_view(0, len - self.chunk_len);
+                Some(chunk)
+            }
+        }
+    }
+}
+
+impl<'view> ExactSizeIterator for BitVecViewChunksExact<'view> {}
+
+pub struct BitVecViewRChunks<'view> {
+    pub(crate) view: BitVecView<'view>,
+    pub(crate) chunk_len: usize,
+}
+
+impl<'view> Iterator for BitVecViewRChunks<'view> {
+    type Item = BitVecView<'view>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let take = self.chunk_len.min(len);
+                let chunk = self.view.sub_view(len - take, take);
+                self.view = self.view.sub_view(0, len - take);
+                Some(chunk)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.view.len.div_ceil(self.chunk_len);
+        (len, Some(len))
+    }
+}
+
+impl<'view> DoubleEndedIterator for BitVecViewRChunks<'view> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let take = match len % self.chunk_len {
+                    0 => self.chunk_len,
+                    short => short
+                };
+                let chunk = self.view.sub_view(0, take);
+                self.view = self.view.sub_view(take, len - take);
+                Some(chunk)
+            }
+        }
+    }
+}
+
+impl<'view> ExactSizeIterator for BitVecViewRChunks<'view> {}
+
+pub struct BitVecViewWindows<'view> {
+    pub(crate) view: BitVecView<'view>,
+    pub(crate) window_len: usize,
+}
+
+impl<'view> Iterator for BitVecViewWindows<'view> {
+    type Item = BitVecView<'view>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.view.len < self.window_len {
+            true => None,
+            false => {
+                let window = self.view.sub_view(0, self.window_len);
+                self.view = self.view.sub_view(1, self.view.len - 1);
+                Some(window)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.view.len + 1).saturating_sub(self.window_len);
+        (len, Some(len))
+    }
+}
+
+impl<'view> DoubleEndedIterator for BitVecViewWindows<'view> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.view.len < self.window_len {
+            true => None,
+            false => {
+                let window = self.view.sub_view(self.view.len - self.window_len, self.window_len);
+                self.view = self.view.sub_view(0, self.view.len - 1);
+                Some(window)
+            }
+        }
+    }
+}
+
+impl<'view> ExactSizeIterator for BitVecViewWindows<'view> {}
+
+pub struct BitVecViewArrayChunks<'view, const N: usize> {
+    pub(crate) view: BitVecView<'view>,
+    pub(crate) remainder: BitVecView<'view>,
+}
+
+impl<'view, const N: usize> BitVecViewArrayChunks<'view, N> {
+    const N: usize = {
+        assert!(N > 0, "array chunk length cannot be 0");
+        N
+    };
+
+    /// The elements at the end that don't fill a whole array
+    #[inline(always)]
+    pub fn remainder(&self) -> BitVecView<'view> {
+        self.remainder.clone()
+    }
+}
+
+impl<'view, const N: usize> Iterator for BitVecViewArrayChunks<'view, N> {
+    type Item = [usize; N];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let vals = unsafe {self.view.read_array::<N>(0)};
+                self.view = self.view.sub_view(N, len - N);
+                Some(vals)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.view.len / Self::N;
+        (len, Some(len))
+    }
+}
+
+impl<'view, const N: usize> DoubleEndedIterator for BitVecViewArrayChunks<'view, N> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.view.len {
+            0 => None,
+            len => {
+                let vals = unsafe {self.view.read_array::<N>(len - N)};
+                self.view = self.view.sub_view(0, len - N);
+                Some(vals)
+            }
+        }
+    }
+}
+
+impl<'view, const N: usize> ExactSizeIterator for BitVecViewArrayChunks<'view, N> {}
+
+pub struct BitVecViewArrayWindows<'view, const N: usize> {
+    pub(crate) view: BitVecView<'view>,
+}
+
+impl<'view, const N: usize> BitVecViewArrayWindows<'view, N> {
+    const N: usize = {
+        assert!(N > 0, "array window length cannot be 0");
+        N
+    };
+}
+
+impl<'view, const N: usize> Iterator for BitVecViewArrayWindows<'view, N> {
+    type Item = [usize; N];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.view.len < Self::N {
+            true => None,
+            false => {
+                let vals = unsafe {self.view.read_array::<N>(0)};
+                self.view = self.view.sub_view(1, self.view.len - 1);
+                Some(vals)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.view.len + 1).saturating_sub(Self::N);
+        (len, Some(len))
+    }
+}
+
+impl<'view, const N: usize> DoubleEndedIterator for BitVecViewArrayWindows<'view, N> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.view.len < Self::N {
+            true => None,
+            false => {
+                let vals = unsafe {self.view.read_array::<N>(self.view.len - N)};
+                self.view = self.view.sub_view(0, self.view.len - 1);
+                Some(vals)
+            }
+        }
+    }
+}
+
+impl<'view, const N: usize> ExactSizeIterator for BitVecViewArrayWindows<'view, N> {}
+
+pub struct BitVecViewBlockChunks<'view> {
+    pub(crate) proto: BitProto,
+    pub(crate) ptr: NonNull<usize>,
+    pub(crate) view_start: usize,
+    pub(crate) view_end_excluded: usize,
+    pub(crate) start_block: usize,
+    pub(crate) end_block: usize,
+    pub(crate) _borrow: PhantomData<&'view [usize]>,
+}
+
+impl<'view> BitVecViewBlockChunks<'view> {
+    #[inline(always)]
+    fn block_chunk(&self, block_idx: usize) -> (usize, Range<usize>) {
+        let block_first_bit = BitUtil::calc_total_bits_in_num_usize(block_idx);
+        let first_whole = block_first_bit.div_ceil(self.proto.BITS);
+        let end_whole = (block_first_bit + BitUtil::USIZE_BITS) / self.proto.BITS;
+        let start = first_whole.clamp(self.view_start, self.view_end_excluded) - self.view_start;
+        let end = end_whole.clamp(self.view_start, self.view_end_excluded) - self.view_start;
+        let block = unsafe {ptr::read(self.ptr.as_ptr().add(block_idx))};
+        (block, start..end.max(start))
+    }
+}
+
+// SAFETY: a BitVecViewBlockChunks only ever reads from memory that is borrowed immutably for `'view`, exactly like a `&'view [usize]`
+unsafe impl<'view> Send for BitVecViewBlockChunks<'view> {}
+unsafe impl<'view> Sync for BitVecViewBlockChunks<'view> {}
+
+impl<'view> Iterator for BitVecViewBlockChunks<'view> {
+    type Item = (usize, Range<usize>);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.start_block == self.end_block {
+            true => None,
+            false => {
+                let chunk = self.block_chunk(self.start_block);
+                self.start_block += 1;
+                Some(chunk)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end_block - self.start_block;
+        (len, Some(len))
+    }
+}
+
+impl<'view> DoubleEndedIterator for BitVecViewBlockChunks<'view> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.start_block == self.end_block {
+            true => None,
+            false => {
+                self.end_block -= 1;
+                Some(self.block_chunk(self.end_block))
+            }
+        }
+    }
+}
+
+impl<'view> ExactSizeIterator for BitVecViewBlockChunks<'view> {}
+
+pub type TypedBitVecViewChunks<'view, T> = Map<BitVecViewChunks<'view>, fn(BitVecView<'view>) -> TypedBitVecView<'view, T>>;
+pub type TypedBitVecViewChunksExact<'view, T> = Map<BitVecViewChunksExact<'view>, fn(BitVecView<'view>) -> TypedBitVecView<'view, T>>;
+pub type TypedBitVecViewRChunks<'view, T> = Map<BitVecViewRChunks<'view>, fn(BitVecView<'view>) -> TypedBitVecView<'view, T>>;
+pub type TypedBitVecViewWindows<'view, T> = Map<BitVecViewWindows<'view>, fn(BitVecView<'view>) -> TypedBitVecView<'view, T>>;
+pub type TypedBitVecViewArrayChunks<'view, T, const N: usize> = Map<BitVecViewArrayChunks<'view, N>, fn([usize; N]) -> [<T as TypedBitElem>::Base; N]>;
+pub type TypedBitVecViewArrayWindows<'view, T, const N: usize> = Map<BitVecViewArrayWindows<'view, N>, fn([usize; N]) -> [<T as TypedBitElem>::Base; N]>;
+
+impl<'view, T: TypedBitElem> TypedBitVecView<'view, T> {
+    #[inline(always)]
+    pub fn chunks(&self, chunk_len: usize) -> Result<TypedBitVecViewChunks<'view, T>, String> {
+        Ok(self.0.chunks(chunk_len)?.map(Self::from_untyped as fn(BitVecView<'view>) -> Self))
+    }
+
+    #[inline(always)]
+    pub fn chunks_exact(&self, chunk_len: usize) -> Result<TypedBitVecViewChunksExact<'view, T>, String> {
+        Ok(self.0.chunks_exact(chunk_len)?.map(Self::from_untyped as fn(BitVecView<'view>) -> Self))
+    }
+
+    #[inline(always)]
+    pub fn rchunks(&self, chunk_len: usize) -> Result<TypedBitVecViewRChunks<'view, T>, String> {
+        Ok(self.0.rchunks(chunk_len)?.map(Self::from_untyped as fn(BitVecView<'view>) -> Self))
+    }
+
+    #[inline(always)]
+    pub fn windows(&self, window_len: usize) -> Result<TypedBitVecViewWindows<'view, T>, String> {
+        Ok(self.0.windows(window_len)?.map(Self::from_untyped as fn(BitVecView<'view>) -> Self))
+    }
+
+    #[inline(always)]
+    pub fn array_chunks<const N: usize>(&self) -> TypedBitVecViewArrayChunks<'view, T, N> {
+        self.0.array_chunks::<N>().map(decode_array::<T, N> as fn([usize; N]) -> [T::Base; N])
+    }
+
+    #[inline(always)]
+    pub fn array_windows<const N: usize>(&self) -> TypedBitVecViewArrayWindows<'view, T, N> {
+        self.0.array_windows::<N>().map(decode_array::<T, N> as fn([usize; N]) -> [T::Base; N])
+    }
+
+    #[inline(always)]
+    pub fn block_chunks(&self) -> BitVecViewBlockChunks<'view> {
+        self.0.block_chunks()
+    }
+
+    #[inline(always)]
+    pub(crate) fn from_untyped(view: BitVecView<'view>) -> Self {
+        TypedBitVecView(view, PhantomData)
+    }
+}
+
+#[inline(always)]
+fn decode_array<T: TypedBitElem, const N: usize>(bits: [usize; N]) -> [T::Base; N] {
+    bits.map(T::bits_to_val)
+}
@@ -0,0 +1,136 @@
+use crate::{
+    BitProto,
+    NonNull,
+    NonZeroUsize,
+    PhantomData,
+    ptr,
+};
+
+pub struct BitVecViewIter<'view> {
+    pub(crate) proto: BitProto,
+    pub(crate) ptr: NonNull<usize>,
+    pub(crate) start: usize,
+    pub(crate) end_excluded: usize,
+    pub(crate) _borrow: PhantomData<&'view [usize]>,
+}
+
+// SAFETY: a BitVecViewIter only ever reads from memory that is borrowed immutably for `'view`, exactly like a `&'view [usize]`
+unsafe impl<'view> Send for BitVecViewIter<'view> {}
+unsafe impl<'view> Sync for BitVecViewIter<'view> {}
+
+impl<'view> Iterator for BitVecViewIter<'view> {
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.start == self.end_excluded {
+            true => None,
+            false => {
+                let idx_proxy = BitProto::idx_proxy(self.proto, self.start);
+                self.start += 1;
+                Some(unsafe {idx_proxy.read_val(self.ptr.as_ptr())})
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end_excluded - self.start;
+        (len, Some(len))
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.advance_by(n) {
+            Ok(()) => self.next(),
+            Err(_) => None,
+        }
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.end_excluded - self.start
+    }
+}
+
+impl<'view> DoubleEndedIterator for BitVecViewIter<'view> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.start == self.end_excluded {
+            true => None,
+            false => {
+                self.end_excluded -= 1;
+                let idx_proxy = BitProto::idx_proxy(self.proto, self.end_excluded);
+                Some(unsafe {idx_proxy.read_val(self.ptr.as_ptr())})
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match self.advance_back_by(n) {
+            Ok(()) => self.next_back(),
+            Err(_) => None,
+        }
+    }
+}
+
+impl<'view> ExactSizeIterator for BitVecViewIter<'view> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.end_excluded - self.start
+    }
+}
+
+impl<'view> BitVecViewIter<'view> {
+    /// Skip the next `n` elements without reading them, returning the number of elements that could not be skipped
+    /// as an `Err` if fewer than `n` remain (mirrors the unstable `Iterator::advance_by()`)
+    #[inline]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        self.start += step;
+        match NonZeroUsize::new(n - step) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    /// Skip the last `n` elements without reading them, returning the number of elements that could not be skipped
+    /// as an `Err` if fewer than `n` remain (mirrors the unstable `DoubleEndedIterator::advance_back_by()`)
+    #[inline]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        self.end_excluded -= step;
+        match NonZeroUsize::new(n - step) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(self) -> Vec<usize> {
+        self.collect_vec_with(|bits| bits)
+    }
+
+    #[inline]
+    pub(crate) fn collect_vec_with<T, F: FnMut(usize) -> T>(mut self, mut map: F) -> Vec<T> {
+        let len = self.len();
+        let mut out = Vec::with_capacity(len);
+        let out_ptr: *mut T = out.as_mut_ptr();
+        for offset in 0..len {
+            let idx_proxy = BitProto::idx_proxy(self.proto, self.start + offset);
+            unsafe {
+                ptr::write(out_ptr.add(offset), map(idx_proxy.read_val(self.ptr.as_ptr())));
+                out.set_len(offset + 1);
+            }
+        }
+        self.start = self.end_excluded;
+        out
+    }
+}
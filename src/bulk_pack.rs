@@ -0,0 +1,167 @@
+use crate::{
+    BitProto,
+    BitUtil,
+    ptr,
+};
+
+/// An unsigned integer type that packed elements can be bulk-copied to and from with
+/// `unpack_into()` and `pack_from()`
+pub trait PackedUint: Copy {
+    /// The number of bits in this type, elements wider than this cannot be unpacked into it
+    const BITS: usize;
+    fn from_bits(bits: usize) -> Self;
+    fn into_bits(self) -> usize;
+}
+
+macro_rules! impl_packed_uint {
+    ($($TYPE:ty),+) => {$(
+        impl PackedUint for $TYPE {
+            const BITS: usize = <$TYPE>::BITS as usize;
+            #[inline(always)]
+            fn from_bits(bits: usize) -> Self {
+                bits as $TYPE
+            }
+            #[inline(always)]
+            fn into_bits(self) -> usize {
+                self as usize
+            }
+        }
+    )+};
+}
+
+impl_packed_uint!(u8, u16, u32, u64, usize);
+
+#[inline(always)]
+pub(crate) fn check_unpack_buffer<T: PackedUint>(proto: BitProto, count: usize, out_len: usize) -> Result<(), String> {
+    if proto.BITS > T::BITS {
+        return Err(format!("cannot unpack {} bit elements into a {} bit integer buffer", proto.BITS, T::BITS));
+    }
+    match count == out_len {
+        true => Ok(()),
+        false => Err(format!("buffer length does not match range length for unpack_into: (buffer len) {} != {} (range len)", out_len, count))
+    }
+}
+
+/// `USIZE_BITS` elements of `bits` bits each start and end on a block boundary and span exactly `bits` blocks,
+/// so a whole group is decoded by streaming its blocks through a double-width buffer
+#[inline(always)]
+unsafe fn unpack_group<T: PackedUint>(bits: usize, mask: usize, blocks: *const usize, out: *mut T) {
+    let mut buf: u128 = 0;
+    let mut have = 0;
+    let mut block = 0;
+    for lane in 0..BitUtil::USIZE_BITS {
+        if have < bits {
+            buf |= (ptr::read(blocks.add(block)) as u128) << have;
+            block += 1;
+            have += BitUtil::USIZE_BITS;
+        }
+        ptr::write(out.add(lane), T::from_bits(buf as usize & mask));
+        buf >>= bits;
+        have -= bits;
+    }
+}
+
+#[inline(always)]
+unsafe fn pack_group<T: PackedUint>(bits: usize, src: *const T, blocks: *mut usize) {
+    let mut buf: usize = 0;
+    let mut have = 0;
+    let mut block = 0;
+    for lane in 0..BitUtil::USIZE_BITS {
+        let val = ptr::read(src.add(lane)).into_bits();
+        buf |= val << have;
+        have += bits;
+        if have >= BitUtil::USIZE_BITS {
+            ptr::write(blocks.add(block), buf);
+            block += 1;
+            have -= BitUtil::USIZE_BITS;
+            // The bits of `val` that did not fit in the block just written start the next one
+            buf = BitUtil::right_shift_discard_if_ubits(val, bits - have);
+        }
+    }
+}
+
+/// Elements before the first block-aligned index and after the last whole group are copied one at a time,
+/// everything in between is handled a group of blocks at a time
+#[inline(always)]
+unsafe fn unpack_run<T: PackedUint>(proto: BitProto, bits: usize, blocks: *const usize, start: usize, out: &mut [T]) {
+    let len = out.len();
+    let period = BitProto::block_aligned_period(proto);
+    let head = ((period - (start % period)) % period).min(len);
+    let mut done = 0;
+    while done < head {
+        out[done] = T::from_bits(BitProto::idx_proxy(proto, start + done).read_val(blocks));
+        done += 1;
+    }
+    if len - done >= BitUtil::USIZE_BITS {
+        let mut block_ptr = blocks.add(BitProto::idx_proxy(proto, start + done).real_idx);
+        while len - done >= BitUtil::USIZE_BITS {
+            unpack_group(bits, proto.MASK, block_ptr, out.as_mut_ptr().add(done));
+            block_ptr = block_ptr.add(bits);
+            done += BitUtil::USIZE_BITS;
+        }
+    }
+    while done < len {
+        out[done] = T::from_bits(BitProto::idx_proxy(proto, start + done).read_val(blocks));
+        done += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn pack_run<T: PackedUint>(proto: BitProto, bits: usize, blocks: *mut usize, start: usize, src: &[T]) {
+    let len = src.len();
+    let period = BitProto::block_aligned_period(proto);
+    let head = ((period - (start % period)) % period).min(len);
+    let mut done = 0;
+    while done < head {
+        BitProto::idx_proxy(proto, start + done).write_val(blocks, src[done].into_bits());
+        done += 1;
+    }
+    if len - done >= BitUtil::USIZE_BITS {
+        let mut block_ptr = blocks.add(BitProto::idx_proxy(proto, start + done).real_idx);
+        while len - done >= BitUtil::USIZE_BITS {
+            pack_group(bits, src.as_ptr().add(done), block_ptr);
+            block_ptr = block_ptr.add(bits);
+            done += BitUtil::USIZE_BITS;
+        }
+    }
+    while done < len {
+        BitProto::idx_proxy(proto, start + done).write_val(blocks, src[done].into_bits());
+        done += 1;
+    }
+}
+
+unsafe fn unpack_fixed<T: PackedUint, const BITS: usize>(proto: BitProto, blocks: *const usize, start: usize, out: &mut [T]) {
+    unpack_run(proto, BITS, blocks, start, out)
+}
+
+unsafe fn pack_fixed<T: PackedUint, const BITS: usize>(proto: BitProto, blocks: *mut usize, start: usize, src: &[T]) {
+    pack_run(proto, BITS, blocks, start, src)
+}
+
+macro_rules! dispatch_width {
+    ($KERNEL:ident, $RUN:ident, $PROTO:ident, $BLOCKS:ident, $START:ident, $BUF:ident; $($BITS:literal),+) => {
+        match $PROTO.BITS {
+            $($BITS => $KERNEL::<T, $BITS>($PROTO, $BLOCKS, $START, $BUF),)+
+            bits => $RUN($PROTO, bits, $BLOCKS, $START, $BUF),
+        }
+    };
+}
+
+/// Copy `out.len()` elements starting at element `start` of `blocks` into `out`
+///
+/// Widths `1..=16` use kernels specialized for their exact width, wider elements use the same
+/// block-streaming kernel with the width known only at runtime
+///
+/// ## Safety
+/// `blocks` must hold at least `start + out.len()` elements of `proto`, and `proto.BITS` must be `<= T::BITS`
+pub(crate) unsafe fn unpack_blocks<T: PackedUint>(proto: BitProto, blocks: *const usize, start: usize, out: &mut [T]) {
+    dispatch_width!(unpack_fixed, unpack_run, proto, blocks, start, out; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)
+}
+
+/// Write every value in `src` over the elements starting at element `start` of `blocks`
+///
+/// ## Safety
+/// `blocks` must hold at least `start + src.len()` elements of `proto`, and every value in `src` must be `<= proto.MASK`
+pub(crate) unsafe fn pack_blocks<T: PackedUint>(proto: BitProto, blocks: *mut usize, start: usize, src: &[T]) {
+    dispatch_width!(pack_fixed, pack_run, proto, blocks, start, src; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)
+}
@@ -1,12 +1,30 @@
 use std::ops::RangeFrom;
 
 use crate::{
+    PackedUint,
     BitProto,
+    BitVecView,
+    BitVecViewChunks,
+    BitVecViewChunksExact,
+    BitVecViewRChunks,
+    BitVecViewWindows,
+    BitVecViewBlockChunks,
+    BitVecViewIter,
     RawBitVec,
+    RepackMode,
+    SignedIter,
     CProtoBitVecDrain,
     CProtoBitVecIter,
+    LProtoBitVec,
+    SProtoBitVec,
+    TypedBitElem,
+    TypedBitVec,
+    TryFromBitVecError,
+    ConstGrowth,
+    OneAndHalfGrowth,
     Range,
-    ManuallyDrop
+    ManuallyDrop,
+    PhantomData
 };
 
 /// ## `CProtoBitVec`: "Constant Prototype Bitwise Vector"  
@@ -24,16 +42,21 @@ use crate::{
 /// ### Cons
 /// - Every separate value of `BIT_WIDTH` creates a distinct type with its own copy of all methods (larger binary)
 /// - Cannot store [`CProtoBitVec`]'s with diferent `BIT_WIDTH`'s in the same homogenous collection (`Array`, [`Vec`], [`HashMap`](std::collections::HashMap), etc.)
-pub struct CProtoBitVec<const BIT_WIDTH: usize>(pub(crate) RawBitVec);
+pub struct CProtoBitVec<const BIT_WIDTH: usize, G: ConstGrowth = OneAndHalfGrowth>(pub(crate) RawBitVec, pub(crate) PhantomData<G>);
 
-impl<const BIT_WIDTH: usize> CProtoBitVec<BIT_WIDTH> {
-    pub const PROTO: BitProto = BitProto::create(BIT_WIDTH);
+impl<const BIT_WIDTH: usize, G: ConstGrowth> CProtoBitVec<BIT_WIDTH, G> {
+    pub const PROTO: BitProto = BitProto::create(BIT_WIDTH).with_growth(G::POLICY);
 
     #[inline(always)]
     pub fn len(&self) -> usize {
         self.0.len
     }
 
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        Self::PROTO
+    }
+
     #[inline(always)]
     pub fn cap(&self) -> usize {
         unsafe {self.0.cap(Self::PROTO)}
@@ -46,12 +69,19 @@ impl<const BIT_WIDTH: usize> CProtoBitVec<BIT_WIDTH> {
 
     #[inline(always)]
     pub fn new() -> Self {
-        Self(RawBitVec::new())
+        // Evaluating `PROTO` here turns an invalid `BIT_WIDTH` into a compile error for any code that creates the vec
+        let _ = Self::PROTO;
+        Self(RawBitVec::new(), PhantomData)
     }
 
     #[inline(always)]
     pub fn with_capacity(cap: usize) -> Self {
-        Self(RawBitVec::with_capacity(Self::PROTO, cap))
+        Self(RawBitVec::with_capacity(Self::PROTO, cap), PhantomData)
+    }
+
+    #[inline(always)]
+    pub fn from_elem(val: usize, count: usize) -> Result<Self, String> {
+        Ok(Self(unsafe {RawBitVec::from_elem(Self::PROTO, val, count)?}, PhantomData))
     }
 
     #[inline(always)]
@@ -144,27 +174,27 @@ impl<const BIT_WIDTH: usize> CProtoBitVec<BIT_WIDTH> {
     #[inline(always)]
     pub fn remove_range(&mut self, idx_range: Range<usize>) -> Result<Self, String> {
         match unsafe{self.0.remove_range(Self::PROTO, idx_range)} {
-            Ok(raw) => Ok(Self(raw)),
+            Ok(raw) => Ok(Self(raw, PhantomData)),
             Err(e) => Err(e),
         }
     }
 
     #[inline(always)]
     pub unsafe fn remove_range_unchecked(&mut self, idx_range: Range<usize>) -> Self {
-        Self(self.0.remove_range_unchecked(Self::PROTO, idx_range))
+        Self(self.0.remove_range_unchecked(Self::PROTO, idx_range), PhantomData)
     }
 
     #[inline(always)]
     pub fn trim_range(&mut self, idx_range: RangeFrom<usize>) -> Result<Self, String> {
         match unsafe{self.0.trim_range(Self::PROTO, idx_range)} {
-            Ok(raw) => Ok(Self(raw)),
+            Ok(raw) => Ok(Self(raw, PhantomData)),
             Err(e) => Err(e),
         }
     }
 
     #[inline(always)]
     pub unsafe fn trim_range_unchecked(&mut self, idx_range: RangeFrom<usize>) -> Self {
-        Self(self.0.trim_range_unchecked(Self::PROTO, idx_range))
+        Self(self.0.trim_range_unchecked(Self::PROTO, idx_range), PhantomData)
     }
 
     #[inline(always)]
@@ -240,16 +270,163 @@ impl<const BIT_WIDTH: usize> CProtoBitVec<BIT_WIDTH> {
         self.0.set_unchecked(Self::PROTO, idx, val)
     }
 
+    #[inline(always)]
+    pub fn push_signed(&mut self, val: isize) -> Result<(), String> {
+        unsafe {self.0.push_signed(Self::PROTO, val)}
+    }
+
+    #[inline(always)]
+    pub fn get_signed(&self, idx: usize) -> Result<isize, String> {
+        unsafe {self.0.get_signed(Self::PROTO, idx)}
+    }
+
+    #[inline(always)]
+    pub fn set_signed(&mut self, idx: usize, val: isize) -> Result<(), String> {
+        unsafe {self.0.set_signed(Self::PROTO, idx, val)}
+    }
+
     #[inline]
     pub fn discard_from_end(&mut self, count: usize) {
         self.0.discard_from_end(count)
     }
 
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.0.truncate(new_len)
+    }
+
+    #[inline(always)]
+    pub fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+        unsafe {self.0.reserve(Self::PROTO, extra_elements)}
+    }
+
+    #[inline(always)]
+    pub fn first(&self) -> Result<usize, String> {
+        unsafe {self.0.first(Self::PROTO)}
+    }
+
+    #[inline(always)]
+    pub fn last(&self) -> Result<usize, String> {
+        unsafe {self.0.last(Self::PROTO)}
+    }
+
+    #[inline(always)]
+    pub fn resize(&mut self, new_len: usize, val: usize) -> Result<(), String> {
+        unsafe {self.0.resize(Self::PROTO, new_len, val)}
+    }
+
+    #[inline(always)]
+    pub fn fill(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.0.fill(Self::PROTO, val)}
+    }
+
+    #[inline(always)]
+    pub fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+        unsafe {self.0.extend_from_within(Self::PROTO, src_range)}
+    }
+
+    #[inline(always)]
+    pub fn split_off(&mut self, at: usize) -> Result<Self, String> {
+        let raw = unsafe {self.0.split_off(Self::PROTO, at)?};
+        Ok(Self(raw, PhantomData))
+    }
+
+    #[inline(always)]
+    pub fn reverse(&mut self) {
+        unsafe {self.0.reverse(Self::PROTO)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        unsafe {self.0.rotate_left(Self::PROTO, mid)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        unsafe {self.0.rotate_right(Self::PROTO, count)}
+    }
+
+    #[inline(always)]
+    pub fn fill_range(&mut self, range: Range<usize>, val: usize) -> Result<(), String> {
+        unsafe {self.0.fill_range(Self::PROTO, range, val)}
+    }
+
+    #[inline(always)]
+    pub fn unpack_into<U: PackedUint>(&self, range: Range<usize>, out: &mut [U]) -> Result<(), String> {
+        unsafe {self.0.unpack_into(Self::PROTO, range, out)}
+    }
+
+    #[inline(always)]
+    pub fn pack_from<U: PackedUint>(&mut self, idx: usize, src: &[U]) -> Result<(), String> {
+        unsafe {self.0.pack_from(Self::PROTO, idx, src)}
+    }
+
+    #[inline(always)]
+    pub fn repeat(&self, count: usize) -> Result<Self, String> {
+        let raw = unsafe {self.0.repeat(Self::PROTO, count)?};
+        Ok(Self(raw, PhantomData))
+    }
+
     #[inline(always)]
     pub fn drain<'vec>(&'vec mut self) -> CProtoBitVecDrain<'vec, BIT_WIDTH> {
         CProtoBitVecDrain(self.0.drain())
     }
 
+    #[inline(always)]
+    pub fn view(&self) -> BitVecView<'_> {
+        unsafe {self.0.view(Self::PROTO)}
+    }
+
+    #[inline(always)]
+    pub fn chunks(&self, chunk_len: usize) -> Result<BitVecViewChunks<'_>, String> {
+        self.view().chunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn chunks_exact(&self, chunk_len: usize) -> Result<BitVecViewChunksExact<'_>, String> {
+        self.view().chunks_exact(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn rchunks(&self, chunk_len: usize) -> Result<BitVecViewRChunks<'_>, String> {
+        self.view().rchunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn windows(&self, window_len: usize) -> Result<BitVecViewWindows<'_>, String> {
+        self.view().windows(window_len)
+    }
+
+    #[inline(always)]
+    pub fn block_chunks(&self) -> BitVecViewBlockChunks<'_> {
+        self.view().block_chunks()
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> BitVecViewIter<'_> {
+        self.view().into_iter()
+    }
+
+    #[inline(always)]
+    pub fn iter_signed(&self) -> SignedIter<BitVecViewIter<'_>> {
+        SignedIter { proto: self.proto(), iter: self.iter() }
+    }
+
+    #[inline(always)]
+    pub fn into_iter_signed(self) -> SignedIter<CProtoBitVecIter<BIT_WIDTH>> {
+        SignedIter { proto: self.proto(), iter: self.into_iter() }
+    }
+
+    #[inline(always)]
+    pub fn repack<const NEW_BIT_WIDTH: usize>(&self, mode: RepackMode) -> Result<CProtoBitVec<NEW_BIT_WIDTH, G>, String> {
+        Ok(CProtoBitVec(unsafe {self.0.repack(Self::PROTO, CProtoBitVec::<NEW_BIT_WIDTH, G>::PROTO, mode)?}, PhantomData))
+    }
+
     #[inline(always)]
     pub unsafe fn into_raw(self) -> RawBitVec {
         let nodrop_self = ManuallyDrop::new(self);
@@ -259,9 +436,16 @@ impl<const BIT_WIDTH: usize> CProtoBitVec<BIT_WIDTH> {
             true_cap: nodrop_self.0.true_cap 
         }
     }
+
+    /// ## Safety
+    /// `raw` must have only ever been used with a [`BitProto`] of the same bit width as `BIT_WIDTH`
+    #[inline(always)]
+    pub unsafe fn from_raw(raw: RawBitVec) -> Self {
+        Self(raw, PhantomData)
+    }
 }
 
-impl<const BIT_WIDTH: usize> IntoIterator for CProtoBitVec<BIT_WIDTH> {
+impl<const BIT_WIDTH: usize, G: ConstGrowth> IntoIterator for CProtoBitVec<BIT_WIDTH, G> {
     type Item = usize;
 
     type IntoIter = CProtoBitVecIter<BIT_WIDTH>;
@@ -272,7 +456,43 @@ impl<const BIT_WIDTH: usize> IntoIterator for CProtoBitVec<BIT_WIDTH> {
     }
 }
 
-impl<const BIT_WIDTH: usize> Drop for CProtoBitVec<BIT_WIDTH> {
+impl<const BIT_WIDTH: usize, G: ConstGrowth> Drop for CProtoBitVec<BIT_WIDTH, G> {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVec::drop() will take care of the allocation */}
-}
\ No newline at end of file
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> TryFrom<LProtoBitVec> for CProtoBitVec<BIT_WIDTH, G> {
+    type Error = TryFromBitVecError<LProtoBitVec>;
+
+    #[inline(always)]
+    fn try_from(bitvec: LProtoBitVec) -> Result<Self, Self::Error> {
+        if let Err(err) = BitProto::check_same_width(bitvec.proto, Self::PROTO) {
+            return Err(TryFromBitVecError::new(bitvec, err));
+        }
+        Ok(unsafe {Self::from_raw(bitvec.into_raw())})
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> TryFrom<SProtoBitVec> for CProtoBitVec<BIT_WIDTH, G> {
+    type Error = TryFromBitVecError<SProtoBitVec>;
+
+    #[inline(always)]
+    fn try_from(bitvec: SProtoBitVec) -> Result<Self, Self::Error> {
+        if let Err(err) = BitProto::check_same_width(*bitvec.proto, Self::PROTO) {
+            return Err(TryFromBitVecError::new(bitvec, err));
+        }
+        Ok(unsafe {Self::from_raw(bitvec.into_raw())})
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth, T: TypedBitElem> TryFrom<TypedBitVec<T>> for CProtoBitVec<BIT_WIDTH, G> {
+    type Error = TryFromBitVecError<TypedBitVec<T>>;
+
+    #[inline(always)]
+    fn try_from(bitvec: TypedBitVec<T>) -> Result<Self, Self::Error> {
+        if let Err(err) = BitProto::check_same_width(T::PROTO, Self::PROTO) {
+            return Err(TryFromBitVecError::new(bitvec, err));
+        }
+        Ok(unsafe {Self::from_raw(bitvec.into_raw())})
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    NonZeroUsize,
     BitProto,
     RawBitVecIter, 
     RawBitVecDrain,
@@ -21,6 +22,21 @@ impl<const BIT_WIDTH: usize> Iterator for CProtoBitVecIter<BIT_WIDTH> {
         let len = self.0.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth(Self::PROTO, n)}
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.0.len()
+    }
 }
 
 impl<const BIT_WIDTH: usize> DoubleEndedIterator for CProtoBitVecIter<BIT_WIDTH> {
@@ -28,6 +44,11 @@ impl<const BIT_WIDTH: usize> DoubleEndedIterator for CProtoBitVecIter<BIT_WIDTH>
     fn next_back(&mut self) -> Option<Self::Item> {
         unsafe {self.0.next_back(Self::PROTO)}
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth_back(Self::PROTO, n)}
+    }
 }
 
 impl<const BIT_WIDTH: usize> ExactSizeIterator for CProtoBitVecIter<BIT_WIDTH> {
@@ -37,6 +58,26 @@ impl<const BIT_WIDTH: usize> ExactSizeIterator for CProtoBitVecIter<BIT_WIDTH> {
     }
 }
 
+impl<const BIT_WIDTH: usize> CProtoBitVecIter<BIT_WIDTH> {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecIter::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecIter::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<usize> {
+        unsafe {self.0.collect_vec_with(Self::PROTO, |bits| bits)}
+    }
+}
+
 impl<const BIT_WIDTH: usize> Drop for CProtoBitVecIter<BIT_WIDTH>  {
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
 }
@@ -58,6 +99,21 @@ impl<'vec, const BIT_WIDTH: usize> Iterator for CProtoBitVecDrain<'vec, BIT_WIDT
         let len = self.0.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth(Self::PROTO, n)}
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.0.len()
+    }
 }
 
 impl<'vec, const BIT_WIDTH: usize> DoubleEndedIterator for CProtoBitVecDrain<'vec, BIT_WIDTH> {
@@ -65,6 +121,11 @@ impl<'vec, const BIT_WIDTH: usize> DoubleEndedIterator for CProtoBitVecDrain<'ve
     fn next_back(&mut self) -> Option<Self::Item> {
         unsafe {self.0.next_back(Self::PROTO)}
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth_back(Self::PROTO, n)}
+    }
 }
 
 impl<'vec, const BIT_WIDTH: usize> ExactSizeIterator for CProtoBitVecDrain<'vec, BIT_WIDTH> {
@@ -74,6 +135,26 @@ impl<'vec, const BIT_WIDTH: usize> ExactSizeIterator for CProtoBitVecDrain<'vec,
     }
 }
 
+impl<'vec, const BIT_WIDTH: usize> CProtoBitVecDrain<'vec, BIT_WIDTH> {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecDrain::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecDrain::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<usize> {
+        unsafe {self.0.collect_vec_with(Self::PROTO, |bits| bits)}
+    }
+}
+
 impl<'vec, const BIT_WIDTH: usize> Drop for CProtoBitVecDrain<'vec, BIT_WIDTH>  {
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
 }
\ No newline at end of file
@@ -0,0 +1,171 @@
+use crate::{
+    BitVecViewIter,
+    LProtoBitVec,
+    proto_for_span,
+};
+
+/// ## `DeltaBitVec`: "Delta-Encoded Bitwise Vector"
+/// A column of `i64` values stored as the packed difference between each value and the one before it, with the full
+/// value of every `interval()`-th element kept as a checkpoint so random access only has to sum the deltas since the
+/// nearest checkpoint
+///
+/// Deltas are stored frame-of-reference style (as `delta - min_delta`), so a sorted column only needs enough bits for
+/// its largest gap, and unsorted columns still work
+///
+/// Built with `collect()`/`FromIterator` (checkpoint every [`DeltaBitVec::DEFAULT_INTERVAL`] elements) or
+/// [`DeltaBitVec::from_iter_with_interval()`]
+///
+/// ### Pros
+/// - Sorted or slowly changing columns (timestamps, counters) take only `log2(max_gap - min_gap + 1)` bits per element
+///   plus one `i64` per checkpoint
+/// - Sequential iteration decodes each element with one addition
+///
+/// ### Cons
+/// - `get()` is O(`interval()`) rather than O(1)
+/// - Read-only once built
+pub struct DeltaBitVec {
+    pub(crate) checkpoints: Vec<i64>,
+    pub(crate) min_delta: i64,
+    pub(crate) deltas: LProtoBitVec,
+    pub(crate) interval: usize,
+}
+
+impl DeltaBitVec {
+    pub const DEFAULT_INTERVAL: usize = 64;
+
+    /// Build a [`DeltaBitVec`] that keeps the full value of every `interval`-th element
+    pub fn from_iter_with_interval<I: IntoIterator<Item = i64>>(iter: I, interval: usize) -> Result<Self, String> {
+        if interval == 0 {
+            return Err(String::from("checkpoint interval for DeltaBitVec cannot be 0"));
+        }
+        let vals: Vec<i64> = iter.into_iter().collect();
+        let deltas = || (1..vals.len()).filter(|idx| idx % interval != 0).map(|idx| vals[idx].wrapping_sub(vals[idx - 1]));
+        let min_delta = deltas().min().unwrap_or(0);
+        let max_delta = deltas().max().unwrap_or(0);
+        let proto = proto_for_span(max_delta.wrapping_sub(min_delta) as u64);
+        // Checkpoint positions keep an unused `0` so element `idx` is always at offset `idx`
+        let offsets: Vec<usize> = (0..vals.len()).map(|idx| match idx % interval {
+            0 => 0,
+            _ => vals[idx].wrapping_sub(vals[idx - 1]).wrapping_sub(min_delta) as usize,
+        }).collect();
+        let mut packed = LProtoBitVec::from_elem(proto, 0, offsets.len())?;
+        packed.pack_from(0, &offsets)?;
+        Ok(Self {
+            checkpoints: vals.iter().copied().step_by(interval).collect(),
+            min_delta,
+            deltas: packed,
+            interval,
+        })
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.deltas.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.deltas.is_empty()
+    }
+
+    /// The number of elements between checkpoints
+    #[inline(always)]
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+
+    /// The number of bits used to store each delta
+    #[inline(always)]
+    pub fn bit_width(&self) -> usize {
+        self.deltas.proto().bit_width()
+    }
+
+    /// Read the element at `idx` by summing the deltas since the nearest checkpoint before it
+    #[inline]
+    pub fn get(&self, idx: usize) -> Result<i64, String> {
+        if idx >= self.len() {
+            return Err(format!("index out of bounds for get: (idx) {} >= {} (len)", idx, self.len()));
+        }
+        let checkpoint_idx = idx - (idx % self.interval);
+        let steps = idx - checkpoint_idx;
+        let offset_sum = self.deltas.view().slice(checkpoint_idx + 1..idx + 1)?.iter()
+            .fold(0i64, |sum, offset| sum.wrapping_add(offset as i64));
+        Ok(self.checkpoints[idx / self.interval]
+            .wrapping_add(self.min_delta.wrapping_mul(steps as i64))
+            .wrapping_add(offset_sum))
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> DeltaBitVecIter<'_> {
+        DeltaBitVecIter {
+            vec: self,
+            iter: self.deltas.iter(),
+            idx: 0,
+            prev: 0,
+        }
+    }
+}
+
+impl FromIterator<i64> for DeltaBitVec {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        Self::from_iter_with_interval(iter, Self::DEFAULT_INTERVAL).unwrap()
+    }
+}
+
+pub struct DeltaBitVecIter<'vec> {
+    pub(crate) vec: &'vec DeltaBitVec,
+    pub(crate) iter: BitVecViewIter<'vec>,
+    pub(crate) idx: usize,
+    pub(crate) prev: i64,
+}
+
+impl<'vec> Iterator for DeltaBitVecIter<'vec> {
+    type Item = i64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.iter.next()?;
+        self.prev = match self.idx % self.vec.interval {
+            0 => self.vec.checkpoints[self.idx / self.vec.interval],
+            _ => self.prev.wrapping_add(self.vec.min_delta).wrapping_add(offset as i64),
+        };
+        self.idx += 1;
+        Some(self.prev)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    /// Jumps to the nearest checkpoint before the target instead of decoding every skipped element
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let target = self.idx.checked_add(n).filter(|target| *target < self.vec.len());
+        match target {
+            None => {
+                let _ = self.iter.advance_by(self.iter.len());
+                self.idx = self.vec.len();
+                None
+            },
+            Some(target) => {
+                let checkpoint_idx = target - (target % self.vec.interval);
+                if checkpoint_idx > self.idx {
+                    let _ = self.iter.advance_by(checkpoint_idx - self.idx);
+                    self.idx = checkpoint_idx;
+                }
+                for _ in self.idx..target {
+                    self.next();
+                }
+                self.next()
+            }
+        }
+    }
+}
+
+impl<'vec> ExactSizeIterator for DeltaBitVecIter<'vec> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    collections::{
+        HashMap,
+        hash_map::RandomState,
+    },
+    hash::{
+        BuildHasher,
+        Hash,
+    },
+};
+
+use crate::{
+    BitProto,
+    BitVecView,
+    BitVecViewIter,
+    LProtoBitVec,
+    RepackMode,
+};
+
+/// ## `DictBitVec`: "Dictionary-Encoded Bitwise Vector"
+/// A vector of arbitrary values stored as a dictionary of the distinct values plus a packed code (an index into the
+/// dictionary) for every element, with the codes kept in the fewest bits that can index the whole dictionary
+///
+/// The code width grows automatically as new distinct values are pushed. Values replaced with `set()` or removed with
+/// `pop()`/`truncate()` stay in the dictionary until [`DictBitVec::compact()`] drops them and re-encodes the codes
+///
+/// ### Pros
+/// - Low-cardinality columns (categories, enum-like strings) store each element in `log2(distinct)` bits, plus one copy
+///   of each distinct value
+/// - `V` only needs `Hash + Eq`, values are never cloned
+///
+/// ### Cons
+/// - A hash lookup on every `push()`/`set()`
+/// - Re-encodes every code whenever the code width grows
+pub struct DictBitVec<V: Hash + Eq, S: BuildHasher = RandomState> {
+    pub(crate) dict: Vec<V>,
+    /// First dictionary code for each hash, further codes with the same hash are linked through `chain`
+    pub(crate) index: HashMap<u64, usize>,
+    pub(crate) chain: Vec<usize>,
+    pub(crate) codes: LProtoBitVec,
+    pub(crate) hasher: S,
+}
+
+impl<V: Hash + Eq> DictBitVec<V> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<V: Hash + Eq> Default for DictBitVec<V> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Hash + Eq, S: BuildHasher> DictBitVec<V, S> {
+    const NO_CODE: usize = usize::MAX;
+
+    #[inline(always)]
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            dict: Vec::new(),
+            index: HashMap::new(),
+            chain: Vec::new(),
+            codes: LProtoBitVec::new(BitProto::create(1)),
+            hasher,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// The number of bits used to store each code
+    #[inline(always)]
+    pub fn bit_width(&self) -> usize {
+        self.codes.proto().bit_width()
+    }
+
+    /// Every distinct value, indexed by code
+    #[inline(always)]
+    pub fn dictionary(&self) -> &[V] {
+        &self.dict
+    }
+
+    /// The packed code of every element
+    #[inline(always)]
+    pub fn codes(&self) -> BitVecView<'_> {
+        self.codes.view()
+    }
+
+    /// The code `val` is stored as, if it is in the dictionary
+    pub fn code_of(&self, val: &V) -> Option<usize> {
+        let mut code = *self.index.get(&self.hasher.hash_one(val))?;
+        while code != Self::NO_CODE {
+            if self.dict[code] == *val {
+                return Some(code);
+            }
+            code = self.chain[code];
+        }
+        None
+    }
+
+    #[inline]
+    pub fn get(&self, idx: usize) -> Result<&V, String> {
+        Ok(&self.dict[self.codes.get(idx)?])
+    }
+
+    #[inline]
+    pub fn push(&mut self, val: V) -> Result<(), String> {
+        let code = self.code_or_insert(val)?;
+        self.codes.push(code)
+    }
+
+    #[inline]
+    pub fn set(&mut self, idx: usize, val: V) -> Result<(), String> {
+        if idx >= self.len() {
+            return Err(format!("index out of bounds for set: (idx) {} >= {} (len)", idx, self.len()));
+        }
+        let code = self.code_or_insert(val)?;
+        self.codes.set(idx, code)
+    }
+
+    /// Remove the last element, returning its value
+    #[inline]
+    pub fn pop(&mut self) -> Result<&V, String> {
+        let code = self.codes.pop()?;
+        Ok(&self.dict[code])
+    }
+
+    #[inline(always)]
+    pub fn truncate(&mut self, len: usize) {
+        self.codes.truncate(len)
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> DictBitVecIter<'_, V> {
+        DictBitVecIter {
+            dict: &self.dict,
+            codes: self.codes.iter(),
+        }
+    }
+
+    /// Drop every dictionary value that no element refers to any more, renumber the remaining values in their original
+    /// order, and re-encode every code in the (possibly narrower) new width
+    pub fn compact(&mut self) -> Result<(), String> {
+        let mut codes = vec![0usize; self.len()];
+        self.codes.unpack_into(0..self.len(), &mut codes)?;
+        let mut remap = vec![Self::NO_CODE; self.dict.len()];
+        for code in codes.iter() {
+            remap[*code] = 0;
+        }
+        for (next_code, new_code) in remap.iter_mut().filter(|new_code| **new_code == 0).enumerate() {
+            *new_code = next_code;
+        }
+        let old_dict = core::mem::take(&mut self.dict);
+        self.index.clear();
+        self.chain.clear();
+        for (val, _) in old_dict.into_iter().zip(remap.iter()).filter(|(_, new_code)| **new_code != Self::NO_CODE) {
+            self.insert_new(val);
+        }
+        for code in codes.iter_mut() {
+            *code = remap[*code];
+        }
+        let mut new_codes = LProtoBitVec::from_elem(Self::code_proto(self.dict.len()), 0, codes.len())?;
+        new_codes.pack_from(0, &codes)?;
+        self.codes = new_codes;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn code_proto(distinct: usize) -> BitProto {
+        BitProto::create_from_state_count(distinct.max(1))
+    }
+
+    #[inline]
+    fn code_or_insert(&mut self, val: V) -> Result<usize, String> {
+        if let Some(code) = self.code_of(&val) {
+            return Ok(code);
+        }
+        let code = self.insert_new(val);
+        if code > self.codes.proto().MASK {
+            self.codes = self.codes.repack(Self::code_proto(self.dict.len()), RepackMode::Checked)?;
+        }
+        Ok(code)
+    }
+
+    /// Add `val` to the dictionary without checking whether it is already there
+    #[inline]
+    fn insert_new(&mut self, val: V) -> usize {
+        let code = self.dict.len();
+        let first = self.index.entry(self.hasher.hash_one(&val)).or_insert(Self::NO_CODE);
+        self.chain.push(*first);
+        *first = code;
+        self.dict.push(val);
+        code
+    }
+}
+
+impl<V: Hash + Eq> FromIterator<V> for DictBitVec<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut dict_vec = Self::new();
+        for val in iter {
+            dict_vec.push(val).unwrap();
+        }
+        dict_vec
+    }
+}
+
+pub struct DictBitVecIter<'vec, V> {
+    pub(crate) dict: &'vec [V],
+    pub(crate) codes: BitVecViewIter<'vec>,
+}
+
+impl<'vec, V> Iterator for DictBitVecIter<'vec, V> {
+    type Item = &'vec V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.codes.next().map(|code| &self.dict[code])
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.codes.size_hint()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.codes.nth(n).map(|code| &self.dict[code])
+    }
+}
+
+impl<'vec, V> DoubleEndedIterator for DictBitVecIter<'vec, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.codes.next_back().map(|code| &self.dict[code])
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.codes.nth_back(n).map(|code| &self.dict[code])
+    }
+}
+
+impl<'vec, V> ExactSizeIterator for DictBitVecIter<'vec, V> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.codes.len()
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    BitProto,
+    BitVecViewIter,
+    CProtoBitVec,
+    LProtoBitVec,
+    RankSelect,
+};
+
+/// ## `EliasFano`: "Elias-Fano Encoded Monotone Sequence"
+/// A non-decreasing sequence of `usize` values, each split into `low_bits()` low bits stored in a packed
+/// [`LProtoBitVec`] and the remaining high bits stored in unary in a [`RankSelect`] bit vector (value `i` with high
+/// part `h` sets bit `h + i`), using about `2 + log2(universe / len)` bits per value
+///
+/// Built from a sorted iterator with [`EliasFano::from_sorted()`]
+///
+/// ### Pros
+/// - Close to the information-theoretic minimum size for sorted sets (posting lists, offsets, sorted ids)
+/// - `get()` in O(log n) and `next_geq()` without decoding the values before the answer
+///
+/// ### Cons
+/// - Read-only once built
+/// - Values must be sorted
+pub struct EliasFano {
+    pub(crate) low_bits: usize,
+    pub(crate) lows: LProtoBitVec,
+    pub(crate) highs: RankSelect,
+    pub(crate) len: usize,
+}
+
+impl EliasFano {
+    /// Encode the values of `sorted`, which must be in non-decreasing order
+    pub fn from_sorted<I: IntoIterator<Item = usize>>(sorted: I) -> Result<Self, String> {
+        let vals: Vec<usize> = sorted.into_iter().collect();
+        if let Some(idx) = (1..vals.len()).find(|idx| vals[*idx] < vals[*idx - 1]) {
+            return Err(format!("values for EliasFano must be sorted: (idx {}) {} < {} (idx {})", idx, vals[idx], vals[idx - 1], idx - 1));
+        }
+        let len = vals.len();
+        let universe = vals.last().map_or(0, |max| max.saturating_add(1));
+        let low_bits = match len == 0 || universe <= len {
+            true => 0,
+            false => (usize::BITS - 1 - (universe / len).leading_zeros()) as usize,
+        };
+        // Width 0 is not a valid BitProto, `lows` stays empty when there are no low bits
+        let mut lows = LProtoBitVec::new(BitProto::create(low_bits.max(1)));
+        if low_bits > 0 {
+            let low_mask = lows.proto().MASK;
+            let low_vals: Vec<usize> = vals.iter().map(|val| val & low_mask).collect();
+            lows = LProtoBitVec::from_elem(lows.proto(), 0, len)?;
+            lows.pack_from(0, &low_vals)?;
+        }
+        let high_len = vals.last().map_or(0, |max| (max >> low_bits) + len);
+        let mut highs = CProtoBitVec::<1>::from_elem(0, high_len)?;
+        for (idx, val) in vals.iter().enumerate() {
+            highs.set((val >> low_bits) + idx, 1)?;
+        }
+        Ok(Self {
+            low_bits,
+            lows,
+            highs: RankSelect::new(highs),
+            len,
+        })
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of low bits of each value stored in the packed vector
+    #[inline(always)]
+    pub fn low_bits(&self) -> usize {
+        self.low_bits
+    }
+
+    #[inline(always)]
+    fn low(&self, idx: usize) -> usize {
+        match self.low_bits {
+            0 => 0,
+            _ => unsafe {self.lows.view().get_unchecked(idx)}
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, idx: usize) -> Result<usize, String> {
+        match self.highs.select1(idx) {
+            Some(high_pos) => Ok(((high_pos - idx) << self.low_bits) | self.low(idx)),
+            None => Err(format!("index out of bounds for get: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// The index and value of the first value `>= target`, or `None` if every value is smaller
+    ///
+    /// Jumps straight to the first value sharing `target`'s high bits, then scans only the values in that bucket
+    pub fn next_geq(&self, target: usize) -> Option<(usize, usize)> {
+        let high = target >> self.low_bits;
+        // Values with high part `high` start right after the `high`-th zero (`high - 1` counting from `0`)
+        let (mut idx, high_pos) = match high {
+            0 => (0, 0),
+            _ => {
+                let zero_pos = self.highs.select0(high - 1)?;
+                (zero_pos + 1 - high, zero_pos + 1)
+            }
+        };
+        let mut iter = self.iter_from_parts(idx, high_pos);
+        loop {
+            let val = iter.next()?;
+            if val >= target {
+                return Some((idx, val));
+            }
+            idx += 1;
+        }
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> EliasFanoIter<'_> {
+        self.iter_from_parts(0, 0)
+    }
+
+    /// Iterate from element `idx`, whose unary high bit is at or after `high_pos` (which must hold a zero or the bit
+    /// for element `idx`)
+    #[inline(always)]
+    fn iter_from_parts(&self, idx: usize, high_pos: usize) -> EliasFanoIter<'_> {
+        let mut highs = self.highs.bits.iter();
+        let _ = highs.advance_by(high_pos);
+        EliasFanoIter {
+            ef: self,
+            highs,
+            high_pos,
+            idx,
+        }
+    }
+}
+
+pub struct EliasFanoIter<'ef> {
+    pub(crate) ef: &'ef EliasFano,
+    pub(crate) highs: BitVecViewIter<'ef>,
+    pub(crate) high_pos: usize,
+    pub(crate) idx: usize,
+}
+
+impl<'ef> Iterator for EliasFanoIter<'ef> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.ef.len {
+            return None;
+        }
+        while self.highs.next()? == 0 {
+            self.high_pos += 1;
+        }
+        self.high_pos += 1;
+        let high = self.high_pos - 1 - self.idx;
+        let val = (high << self.ef.low_bits) | self.ef.low(self.idx);
+        self.idx += 1;
+        Some(val)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.ef.len - self.idx;
+        (remaining, Some(remaining))
+    }
+
+    /// Jumps to the target's unary bit with `select1()` instead of decoding every skipped value
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let target = match self.idx.checked_add(n) {
+            Some(target) if target < self.ef.len => target,
+            _ => {
+                self.idx = self.ef.len;
+                return None;
+            }
+        };
+        let target_pos = self.ef.highs.select1(target)?;
+        let _ = self.highs.advance_by(target_pos - self.high_pos);
+        self.high_pos = target_pos;
+        self.idx = target;
+        self.next()
+    }
+}
+
+impl<'ef> ExactSizeIterator for EliasFanoIter<'ef> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.ef.len - self.idx
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    BitProto,
+    BitVecView,
+    BitVecViewIter,
+    LProtoBitVec,
+    NonZeroUsize,
+};
+
+/// ## `ForBitVec`: "Frame-of-Reference Bitwise Vector"
+/// A column of `i64` values stored as one `base` value (the smallest value) plus a packed offset from it for every
+/// element, each offset stored in the fewest bits that fit `max - min`
+///
+/// Built with `collect()`/`FromIterator`, which scans the values once to pick the base and width
+///
+/// ### Pros
+/// - Values clustered in a narrow range (ids, timestamps within a window) take only `log2(max - min + 1)` bits each
+/// - O(1) random access, the offsets are an ordinary [`LProtoBitVec`]
+///
+/// ### Cons
+/// - One outlier widens every element
+/// - `push()` fails for values outside the range chosen when the vector was built
+pub struct ForBitVec {
+    pub(crate) base: i64,
+    pub(crate) offsets: LProtoBitVec,
+}
+
+impl ForBitVec {
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// The value every offset is added to (the smallest value the vector was built from)
+    #[inline(always)]
+    pub fn base(&self) -> i64 {
+        self.base
+    }
+
+    /// The number of bits used to store each offset
+    #[inline(always)]
+    pub fn bit_width(&self) -> usize {
+        self.offsets.proto().bit_width()
+    }
+
+    /// The largest value that can be stored without rebuilding the vector
+    #[inline(always)]
+    pub fn max_value(&self) -> i64 {
+        self.base.checked_add_unsigned(self.offsets.proto().MASK as u64).unwrap_or(i64::MAX)
+    }
+
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Result<i64, String> {
+        Ok(self.base.wrapping_add(self.offsets.get(idx)? as i64))
+    }
+
+    /// Append `val`, which must be in `base()..=max_value()`
+    #[inline]
+    pub fn push(&mut self, val: i64) -> Result<(), String> {
+        match (self.base..=self.max_value()).contains(&val) {
+            true => self.offsets.push(val.wrapping_sub(self.base) as usize),
+            false => Err(format!("value out of range for ForBitVec: (val) {} not in {}..={}", val, self.base, self.max_value()))
+        }
+    }
+
+    /// The packed offsets from `base()`
+    #[inline(always)]
+    pub fn offsets(&self) -> BitVecView<'_> {
+        self.offsets.view()
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> ForBitVecIter<'_> {
+        ForBitVecIter {
+            base: self.base,
+            iter: self.offsets.iter(),
+        }
+    }
+}
+
+/// The [`BitProto`] with the fewest bits that holds every offset in `0..=span`
+#[inline]
+pub(crate) fn proto_for_span(span: u64) -> BitProto {
+    if span > usize::MAX as u64 {
+        panic!("value range is wider than usize::BITS");
+    }
+    match (span as usize).checked_add(1) {
+        Some(states) => BitProto::create_from_state_count(states),
+        None => BitProto::create(usize::BITS as usize),
+    }
+}
+
+impl FromIterator<i64> for ForBitVec {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        let vals: Vec<i64> = iter.into_iter().collect();
+        let base = vals.iter().copied().min().unwrap_or(0);
+        let max = vals.iter().copied().max().unwrap_or(0);
+        let proto = proto_for_span(max.wrapping_sub(base) as u64);
+        let offsets: Vec<usize> = vals.iter().map(|val| val.wrapping_sub(base) as usize).collect();
+        let mut packed = LProtoBitVec::from_elem(proto, 0, offsets.len()).unwrap();
+        packed.pack_from(0, &offsets).unwrap();
+        Self { base, offsets: packed }
+    }
+}
+
+pub struct ForBitVecIter<'vec> {
+    pub(crate) base: i64,
+    pub(crate) iter: BitVecViewIter<'vec>,
+}
+
+impl<'vec> ForBitVecIter<'vec> {
+    /// Skip the next `n` elements in O(1), see [`BitVecViewIter::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_by(n)
+    }
+}
+
+impl<'vec> Iterator for ForBitVecIter<'vec> {
+    type Item = i64;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|offset| self.base.wrapping_add(offset as i64))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|offset| self.base.wrapping_add(offset as i64))
+    }
+}
+
+impl<'vec> DoubleEndedIterator for ForBitVecIter<'vec> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|offset| self.base.wrapping_add(offset as i64))
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n).map(|offset| self.base.wrapping_add(offset as i64))
+    }
+}
+
+impl<'vec> ExactSizeIterator for ForBitVecIter<'vec> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -11,6 +11,29 @@ or a struct with 4 bools and 1 u8 in the range of 0-7 as a total of 7 bits.
 - [`SProtoBitVec`] : a wrapper that keeps a static reference to the needed [`BitProto`] in every instance
 - [`LProtoBitVec`] : a wrapper that keeps a full copy of the [`BitProto`] in every instance
 - [`TypedBitVec<T: TypedBitElem>`] : a wrapper that not only stores the [`BitProto`] in a monomorphized constant, but the needed functions to translate the raw returned bits into type `<T>`
+- [`ProtoBitVec<P: ProtoSource>`] : a wrapper generic over where its [`BitProto`] comes from ([`ConstProto<BIT_WIDTH>`], [`BitProto`] or `&'static BitProto`)
+- [`BitVecView`] : a borrowed, read-only window into a range of packed elements that does not own its memory, which can be split into sub-views with `chunks()`, `chunks_exact()`, `rchunks()` and `windows()` (or decoded arrays with `array_chunks()`/`array_windows()`), or walked a whole `usize` block at a time with `block_chunks()`
+- [`AtomicBitVec`] : a fixed-length `BitVec` with [`AtomicUsize`](std::sync::atomic::AtomicUsize) blocks whose elements can be loaded, stored, swapped and updated from many threads at once
+- [`ForBitVec`] : an `i64` column stored as a base value plus packed offsets, in the fewest bits that fit `max - min`
+- [`DeltaBitVec`] : an `i64` column stored as packed deltas between neighbouring values, with a full value checkpointed every few elements for random access
+- [`RleBitVec`] : a run-length encoded `BitVec` of packed `(value, run_length)` pairs with sampled rank indexes for O(log n) `get()`, whose `runs()` can be processed without decoding individual elements
+- [`DictBitVec<V>`] : a dictionary of distinct `Hash + Eq` values plus a packed code per element, whose code width grows with the dictionary
+- [`RankSelect`] : a fixed 1-bit [`CProtoBitVec<1>`] with an index for O(1) `rank1()`/`rank0()` and O(log n) `select1()`/`select0()`
+- [`EliasFano`] : a sorted `usize` sequence split into packed low bits and unary high bits in a [`RankSelect`], supporting `get()`, `next_geq()` and iteration
+- [`WaveletMatrix<N>`](WaveletMatrix) : `N` levels of [`RankSelect`] bits over a sequence of `N`-bit symbols, supporting `access()`, `rank()`, `select()`, `quantile()` and `range_freq()`
+- `MmapBitVec` : (requires the `mmap` feature) a `BitVec` whose packed blocks live in a memory-mapped file with a small header
+
+Every safe wrapper implements the [`BitVecLike`] trait, so algorithms can be written once over any of them, and wrappers
+with the same bit width can be converted into one another without copying using `From`/`TryFrom` (a failed `TryFrom`
+returns the original vector inside its [`TryFromBitVecError`])
+
+Widths can be derived instead of picked by hand with the `const` helpers [`BitProto::for_max_value()`], [`BitProto::for_range()`]
+and [`BitProto::for_type()`], for example `CProtoBitVec<{ BitProto::for_type::<u8_as_u3>().bit_width() }>`. An invalid
+`BIT_WIDTH` (or element width) is a compile error as soon as a vector of that type is created
+
+Ranges of untyped elements can be copied to and from plain `u8`/`u16`/`u32`/`u64`/`usize` buffers with `unpack_into()`
+and `pack_from()`, which decode and encode whole blocks at a time (with kernels specialized for widths `1..=16`) instead of
+one element at a time
 
 All versions use `usize` as the underlying data block type to take advantage of any possible arithmetic optimizations on
 native-size words, meaning that the maximum bit-width supported is the same as `usize::BITS`
@@ -28,6 +51,32 @@ By default the `small_int_impls` feature is enabled, providing simple `TypedBitE
 integer types smaller than 16 bits (for example `u8_as_u3` or `i16_as_i11`), and the `large_int_impls` feature can
 be activated to get similar implementations for bit widths less than `usize::BITS`
 
+`TypedBitElem` is also implemented for ASCII `char` (7 bits), `Ordering` (2 bits), `NonZeroU8_as_uN`/`NonZeroU16_as_uN`
+(storing `n - 1`), `Option<E>` (one extra presence bit), and tuples/arrays of other `TypedBitElem`s (their widths
+concatenated, checked against `usize::BITS` at compile time)
+
+[`Ranged<MIN, MAX>`](Ranged) stores any `i64` range that doesn't start at zero as `val - MIN` in the fewest bits needed,
+for example years `1900..=2155` in 8 bits
+
+[`Fixed<I, F>`](Fixed) and [`SFixed<I, F>`](SFixed) store `f32` values as unsigned/signed fixed-point numbers with `I` integer
+and `F` fractional bits, and [`f8_e4m3`], [`f8_e5m2`] and [`bf16`] store them as 8 and 16 bit floats. Each type documents
+how it rounds, and `try_val_to_bits()` rejects values that would otherwise be saturated
+
+[`bit_enum!`](bit_enum) declares a fieldless enum stored in the fewest bits for its variant count, with `TryFrom<usize>` to decode
+bit patterns that might not match a variant
+
+### Thread Safety
+Every `BitVec` variant owns its blocks exclusively, so all of them (and their iterators) are `Send` and `Sync`:
+they can be moved to another thread, and shared immutably between threads, just like a `Vec<usize>`. Mutation
+still requires `&mut self`, so sharing a mutable vector needs the usual `Mutex`/`RwLock`
+
+- `view()` borrows any variant as a [`BitVecView`] (or [`TypedBitVecView`]) that is cheap to clone and can be handed to as many
+  reader threads as needed, for example with `std::thread::scope()`
+- [`AtomicBitVec`] is the only variant that supports writing elements from many threads at once through `&self` (elements that straddle two blocks are guarded by a spin lock rather than being lock-free)
+- With the `rayon` feature every variant gains `par_iter()`, `par_chunks_mut()` (chunks always start on a block
+  boundary, so no two workers ever write to the same `usize`) and the parallel bulk operations `par_fill()`,
+  `par_map()` and `par_count()`
+
 ### Tested Functions
 - [x] new()  
 - [x] with_capacity()  
@@ -57,7 +106,25 @@ be activated to get similar implementations for bit widths less than `usize::BIT
 - [x] replace()  
 - [x] drain()  
 - [x] into_iter()  
+- [x] iterator nth(), nth_back(), advance_by(), collect_vec()  
 - [ ] discard_from_end()  
+- [x] is_empty()  
+- [x] truncate()  
+- [x] reserve()  
+- [x] first()  
+- [x] last()  
+- [x] resize()  
+- [x] fill()  
+- [x] extend_from_within()  
+- [x] split_off()  
+- [x] reverse()  
+- [x] rotate_left()  
+- [x] rotate_right()  
+- [x] from_elem()  
+- [x] repeat()  
+- [x] fill_range()  
+- [x] unpack_into()  
+- [x] pack_from()  
 
 This crate currently has incomplete documentation and is very much in the "unstable" phase. The API may change in the future
 */
@@ -75,6 +142,7 @@ pub(crate) use core::{
     marker::PhantomData,
     cmp::Ordering,
     ops::Range,
+    num::NonZeroUsize,
 };
 
 pub(crate) use std::alloc::{self, Layout, handle_alloc_error};
@@ -86,6 +154,8 @@ mod raw_bitvec;
 pub use raw_bitvec::*;
 mod raw_bitvec_iter;
 pub use raw_bitvec_iter::*;
+mod bulk_pack;
+pub use bulk_pack::*;
 
 mod const_proto_bitvec;
 pub use const_proto_bitvec::*;
@@ -102,12 +172,60 @@ pub use local_proto_bitvec::*;
 mod local_proto_bitvec_iter;
 pub use local_proto_bitvec_iter::*;
 
+mod proto_bitvec;
+pub use proto_bitvec::*;
+
+mod bitvec_like;
+pub use bitvec_like::*;
+
+mod signed_iter;
+pub use signed_iter::*;
+
+mod bitvec_view;
+pub use bitvec_view::*;
+mod bitvec_view_iter;
+pub use bitvec_view_iter::*;
+mod bitvec_view_chunks;
+pub use bitvec_view_chunks::*;
+
+mod atomic_bitvec;
+pub use atomic_bitvec::*;
+
+mod for_bitvec;
+pub use for_bitvec::*;
+mod delta_bitvec;
+pub use delta_bitvec::*;
+mod rle_bitvec;
+pub use rle_bitvec::*;
+mod dict_bitvec;
+pub use dict_bitvec::*;
+mod rank_select;
+pub use rank_select::*;
+mod elias_fano;
+pub use elias_fano::*;
+mod wavelet_matrix;
+pub use wavelet_matrix::*;
+
+#[cfg(feature="mmap")]
+mod mmap_bitvec;
+#[cfg(feature="mmap")]
+pub use mmap_bitvec::*;
+
+#[cfg(feature="rayon")]
+mod par_bitvec;
+#[cfg(feature="rayon")]
+pub use par_bitvec::*;
+
 mod typed_bitvec;
 pub use typed_bitvec::*;
 mod typed_bitvec_iter;
 pub use typed_bitvec_iter::*;
+mod typed_bitvec_view;
+pub use typed_bitvec_view::*;
 mod typed_bitvec_elem;
 pub use typed_bitvec_elem::*;
+mod typed_bitvec_float_elem;
+pub use typed_bitvec_float_elem::*;
 
 mod utils;
 pub(crate) use utils::*;
@@ -1,10 +1,25 @@
 use std::ops::RangeFrom;
 
 use crate::{
+    PackedUint,
     BitProto,
+    BitVecView,
+    BitVecViewChunks,
+    BitVecViewChunksExact,
+    BitVecViewRChunks,
+    BitVecViewWindows,
+    BitVecViewBlockChunks,
+    BitVecViewIter,
+    CProtoBitVec,
+    ConstGrowth,
     RawBitVec,
+    RepackMode,
+    SignedIter,
     LProtoBitVecIter, 
     LProtoBitVecDrain,
+    SProtoBitVec,
+    TypedBitElem,
+    TypedBitVec,
     Range,
     ManuallyDrop
 };
@@ -35,6 +50,11 @@ impl LProtoBitVec {
         self.vec.len
     }
 
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        self.proto
+    }
+
     #[inline(always)]
     pub fn cap(&self) -> usize {
         unsafe {self.vec.cap(self.proto)}
@@ -55,6 +75,11 @@ impl LProtoBitVec {
         Self { proto, vec: RawBitVec::with_capacity(proto, cap) }
     }
 
+    #[inline(always)]
+    pub fn from_elem(proto: BitProto, val: usize, count: usize) -> Result<Self, String> {
+        Ok(Self { proto, vec: unsafe {RawBitVec::from_elem(proto, val, count)?} })
+    }
+
     #[inline(always)]
     pub fn grow_exact_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
         unsafe {self.vec.grow_exact_for_additional_elements_if_needed(self.proto, extra_elements)}
@@ -257,11 +282,114 @@ impl LProtoBitVec {
         self.vec.set_unchecked(self.proto, idx, val)
     }
 
+    #[inline(always)]
+    pub fn push_signed(&mut self, val: isize) -> Result<(), String> {
+        unsafe {self.vec.push_signed(self.proto, val)}
+    }
+
+    #[inline(always)]
+    pub fn get_signed(&self, idx: usize) -> Result<isize, String> {
+        unsafe {self.vec.get_signed(self.proto, idx)}
+    }
+
+    #[inline(always)]
+    pub fn set_signed(&mut self, idx: usize, val: isize) -> Result<(), String> {
+        unsafe {self.vec.set_signed(self.proto, idx, val)}
+    }
+
     #[inline]
     pub fn discard_from_end(&mut self, count: usize) {
         self.vec.discard_from_end(count)
     }
 
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.vec.truncate(new_len)
+    }
+
+    #[inline(always)]
+    pub fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+        unsafe {self.vec.reserve(self.proto, extra_elements)}
+    }
+
+    #[inline(always)]
+    pub fn first(&self) -> Result<usize, String> {
+        unsafe {self.vec.first(self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn last(&self) -> Result<usize, String> {
+        unsafe {self.vec.last(self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn resize(&mut self, new_len: usize, val: usize) -> Result<(), String> {
+        unsafe {self.vec.resize(self.proto, new_len, val)}
+    }
+
+    #[inline(always)]
+    pub fn fill(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.vec.fill(self.proto, val)}
+    }
+
+    #[inline(always)]
+    pub fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+        unsafe {self.vec.extend_from_within(self.proto, src_range)}
+    }
+
+    #[inline(always)]
+    pub fn split_off(&mut self, at: usize) -> Result<Self, String> {
+        let raw = unsafe {self.vec.split_off(self.proto, at)?};
+        Ok(Self {
+            proto: self.proto,
+            vec: raw
+        })
+    }
+
+    #[inline(always)]
+    pub fn reverse(&mut self) {
+        unsafe {self.vec.reverse(self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        unsafe {self.vec.rotate_left(self.proto, mid)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        unsafe {self.vec.rotate_right(self.proto, count)}
+    }
+
+    #[inline(always)]
+    pub fn fill_range(&mut self, range: Range<usize>, val: usize) -> Result<(), String> {
+        unsafe {self.vec.fill_range(self.proto, range, val)}
+    }
+
+    #[inline(always)]
+    pub fn unpack_into<U: PackedUint>(&self, range: Range<usize>, out: &mut [U]) -> Result<(), String> {
+        unsafe {self.vec.unpack_into(self.proto, range, out)}
+    }
+
+    #[inline(always)]
+    pub fn pack_from<U: PackedUint>(&mut self, idx: usize, src: &[U]) -> Result<(), String> {
+        unsafe {self.vec.pack_from(self.proto, idx, src)}
+    }
+
+    #[inline(always)]
+    pub fn repeat(&self, count: usize) -> Result<Self, String> {
+        let raw = unsafe {self.vec.repeat(self.proto, count)?};
+        Ok(Self {
+            proto: self.proto,
+            vec: raw
+        })
+    }
+
     #[inline(always)]
     pub fn drain<'vec>(&'vec mut self) -> LProtoBitVecDrain<'vec> {
         LProtoBitVecDrain{
@@ -270,6 +398,59 @@ impl LProtoBitVec {
         }
     }
 
+    #[inline(always)]
+    pub fn view(&self) -> BitVecView<'_> {
+        unsafe {self.vec.view(self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn chunks(&self, chunk_len: usize) -> Result<BitVecViewChunks<'_>, String> {
+        self.view().chunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn chunks_exact(&self, chunk_len: usize) -> Result<BitVecViewChunksExact<'_>, String> {
+        self.view().chunks_exact(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn rchunks(&self, chunk_len: usize) -> Result<BitVecViewRChunks<'_>, String> {
+        self.view().rchunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn windows(&self, window_len: usize) -> Result<BitVecViewWindows<'_>, String> {
+        self.view().windows(window_len)
+    }
+
+    #[inline(always)]
+    pub fn block_chunks(&self) -> BitVecViewBlockChunks<'_> {
+        self.view().block_chunks()
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> BitVecViewIter<'_> {
+        self.view().into_iter()
+    }
+
+    #[inline(always)]
+    pub fn iter_signed(&self) -> SignedIter<BitVecViewIter<'_>> {
+        SignedIter { proto: self.proto(), iter: self.iter() }
+    }
+
+    #[inline(always)]
+    pub fn into_iter_signed(self) -> SignedIter<LProtoBitVecIter> {
+        SignedIter { proto: self.proto(), iter: self.into_iter() }
+    }
+
+    #[inline(always)]
+    pub fn repack(&self, new_proto: BitProto, mode: RepackMode) -> Result<Self, String> {
+        Ok(Self {
+            proto: new_proto,
+            vec: unsafe {self.vec.repack(self.proto, new_proto, mode)?}
+        })
+    }
+
     #[inline(always)]
     pub unsafe fn into_raw(self) -> RawBitVec {
         let nodrop_self = ManuallyDrop::new(self);
@@ -279,6 +460,13 @@ impl LProtoBitVec {
             true_cap: nodrop_self.vec.true_cap 
         }
     }
+
+    /// ## Safety
+    /// `raw` must have only ever been used with a [`BitProto`] of the same bit width as `proto`
+    #[inline(always)]
+    pub unsafe fn from_raw(raw: RawBitVec, proto: BitProto) -> Self {
+        Self { proto, vec: raw }
+    }
 }
 
 impl IntoIterator for LProtoBitVec {
@@ -298,4 +486,26 @@ impl IntoIterator for LProtoBitVec {
 impl Drop for LProtoBitVec {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVec::drop() will take care of the deallocation */}
-}
\ No newline at end of file
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> From<CProtoBitVec<BIT_WIDTH, G>> for LProtoBitVec {
+    #[inline(always)]
+    fn from(bitvec: CProtoBitVec<BIT_WIDTH, G>) -> Self {
+        unsafe {Self::from_raw(bitvec.into_raw(), CProtoBitVec::<BIT_WIDTH, G>::PROTO)}
+    }
+}
+
+impl From<SProtoBitVec> for LProtoBitVec {
+    #[inline(always)]
+    fn from(bitvec: SProtoBitVec) -> Self {
+        let proto = *bitvec.proto;
+        unsafe {Self::from_raw(bitvec.into_raw(), proto)}
+    }
+}
+
+impl<T: TypedBitElem> From<TypedBitVec<T>> for LProtoBitVec {
+    #[inline(always)]
+    fn from(bitvec: TypedBitVec<T>) -> Self {
+        unsafe {Self::from_raw(bitvec.into_raw(), T::PROTO)}
+    }
+}
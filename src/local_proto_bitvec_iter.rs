@@ -1,4 +1,5 @@
 use crate::{
+    NonZeroUsize,
     BitProto,
     RawBitVecIter, 
     RawBitVecDrain,
@@ -21,6 +22,21 @@ impl Iterator for LProtoBitVecIter {
         let len = self.iter.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.iter.nth(self.proto, n)}
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.iter.len()
+    }
 }
 
 impl DoubleEndedIterator for LProtoBitVecIter {
@@ -28,6 +44,11 @@ impl DoubleEndedIterator for LProtoBitVecIter {
     fn next_back(&mut self) -> Option<Self::Item> {
         unsafe {self.iter.next_back(self.proto)}
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.iter.nth_back(self.proto, n)}
+    }
 }
 
 impl ExactSizeIterator for LProtoBitVecIter {
@@ -37,6 +58,26 @@ impl ExactSizeIterator for LProtoBitVecIter {
     }
 }
 
+impl LProtoBitVecIter {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecIter::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecIter::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<usize> {
+        unsafe {self.iter.collect_vec_with(self.proto, |bits| bits)}
+    }
+}
+
 impl Drop for LProtoBitVecIter  {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
@@ -60,6 +101,21 @@ impl<'vec> Iterator for LProtoBitVecDrain<'vec> {
         let len = self.drain.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.drain.nth(self.proto, n)}
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.drain.len()
+    }
 }
 
 impl<'vec> DoubleEndedIterator for LProtoBitVecDrain<'vec> {
@@ -67,6 +123,11 @@ impl<'vec> DoubleEndedIterator for LProtoBitVecDrain<'vec> {
     fn next_back(&mut self) -> Option<Self::Item> {
         unsafe {self.drain.next_back(self.proto)}
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.drain.nth_back(self.proto, n)}
+    }
 }
 
 impl<'vec> ExactSizeIterator for LProtoBitVecDrain<'vec> {
@@ -76,6 +137,26 @@ impl<'vec> ExactSizeIterator for LProtoBitVecDrain<'vec> {
     }
 }
 
+impl<'vec> LProtoBitVecDrain<'vec> {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecDrain::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.drain.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecDrain::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.drain.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<usize> {
+        unsafe {self.drain.collect_vec_with(self.proto, |bits| bits)}
+    }
+}
+
 impl<'vec> Drop for LProtoBitVecDrain<'vec>  {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
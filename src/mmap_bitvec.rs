@@ -0,0 +1,553 @@
+use std::{
+    fs::File,
+    path::Path,
+};
+
+use memmap2::{
+    Mmap,
+    MmapMut,
+};
+
+use crate::{
+    BitProto,
+    GrowthPolicy,
+    BitVecView,
+    BitVecViewIter,
+    LProtoBitVec,
+    NonNull,
+    Range,
+    size_of,
+};
+
+pub(crate) enum MmapStorage {
+    ReadOnly(Mmap),
+    Writable(MmapMut),
+}
+
+/// ## `MmapBitVec`: "Memory-Mapped Bitwise Vector"  
+/// A `BitVec` whose packed `usize` blocks live in a memory-mapped file rather than a heap allocation
+/// 
+/// The file begins with a small header followed directly by the packed data blocks:
+/// 
+/// | bytes    | contents                                         |
+/// |----------|--------------------------------------------------|
+/// | `0..4`   | bit width of the elements (`u32`)                |
+/// | `4..8`   | `usize::BITS` of the machine that wrote the file |
+/// | `8..16`  | number of elements (`u64`)                       |
+/// | `16..20` | [`GrowthPolicy`] kind (`u32`, see below)         |
+/// | `20..24` | reserved (`0`)                                   |
+/// | `24..32` | [`GrowthPolicy`] parameter (`u64`)               |
+/// | `32..`   | packed `usize` blocks                            |
+/// 
+/// The growth kind is `0` for `Double`, `1` for `OneAndHalf`, `2` for `FixedStep` and `3` for `PageAligned`, with the
+/// step or page size stored as the parameter (`0` for the others), so a reopened file keeps the policy it was created with
+/// 
+/// All header fields and data blocks use native endianness, so files can only be opened on machines with the same
+/// endianness and pointer width as the one that created them (checked when opening)
+/// 
+/// Elements are read and written in-place through the same [`IdxProxy`](crate::IdxProxy) logic used by [`RawBitVec`](crate::RawBitVec),
+/// and appending past the current capacity extends the file according to the [`GrowthPolicy`] of its [`BitProto`]
+/// 
+/// ### Pros
+/// - Tables larger than available memory can be accessed without reading the whole file
+/// - No copying of data when opening an existing table
+/// 
+/// ### Cons
+/// - UNSAFE to create or open, the file must not be modified or truncated by anything else while mapped
+/// - No insertion or removal in the middle, only reading, setting, appending, truncating and the in-place `Vec`
+///   operations (`fill()`, `reverse()`, `rotate_left()`, ...)
+pub struct MmapBitVec {
+    pub(crate) proto: BitProto,
+    pub(crate) file: File,
+    pub(crate) map: MmapStorage,
+    pub(crate) len: usize,
+    pub(crate) true_cap: usize,
+}
+
+impl MmapBitVec {
+    pub(crate) const HEADER_SIZE: usize = 32;
+
+    /// Create (or truncate) the file at `path` and map it as a new, empty, writable [`MmapBitVec`]
+    /// 
+    /// ## Safety
+    /// The file must not be modified, truncated, or mapped by any other process or [`MmapBitVec`] while this instance exists
+    #[inline]
+    pub unsafe fn create<P: AsRef<Path>>(path: P, proto: BitProto) -> Result<Self, String> {
+        Self::create_with_capacity(path, proto, 0)
+    }
+
+    /// Create (or truncate) the file at `path` with enough space for `cap` elements and map it as a new, empty, writable [`MmapBitVec`]
+    /// 
+    /// ## Safety
+    /// The file must not be modified, truncated, or mapped by any other process or [`MmapBitVec`] while this instance exists
+    pub unsafe fn create_with_capacity<P: AsRef<Path>>(path: P, proto: BitProto, cap: usize) -> Result<Self, String> {
+        if cap > proto.MAX_CAPACITY {
+            return Err(format!("BitVec cannot hold {} elements, maximum capacity is {}", cap, proto.MAX_CAPACITY));
+        }
+        let file = match File::options().read(true).write(true).create(true).truncate(true).open(path.as_ref()) {
+            Ok(file) => file,
+            Err(e) => return Err(format!("could not create file {}: {}", path.as_ref().display(), e)),
+        };
+        let true_cap = BitProto::calc_block_count_from_bitwise_count(proto, cap);
+        Self::set_file_len(&file, true_cap)?;
+        let map = match MmapMut::map_mut(&file) {
+            Ok(map) => map,
+            Err(e) => return Err(format!("could not map file {}: {}", path.as_ref().display(), e)),
+        };
+        let mut new_vec = Self {
+            proto,
+            file,
+            map: MmapStorage::Writable(map),
+            len: 0,
+            true_cap
+        };
+        new_vec.write_header();
+        Ok(new_vec)
+    }
+
+    /// Map an existing file at `path` as a writable [`MmapBitVec`]
+    /// 
+    /// ## Safety
+    /// The file must not be modified, truncated, or mapped by any other process or [`MmapBitVec`] while this instance exists
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let file = match File::options().read(true).write(true).open(path.as_ref()) {
+            Ok(file) => file,
+            Err(e) => return Err(format!("could not open file {}: {}", path.as_ref().display(), e)),
+        };
+        let map = match MmapMut::map_mut(&file) {
+            Ok(map) => map,
+            Err(e) => return Err(format!("could not map file {}: {}", path.as_ref().display(), e)),
+        };
+        Self::from_mapped_file(file, MmapStorage::Writable(map))
+    }
+
+    /// Map an existing file at `path` as a read-only [`MmapBitVec`]
+    /// 
+    /// ## Safety
+    /// The file must not be modified or truncated by any other process while this instance exists
+    pub unsafe fn open_read_only<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let file = match File::open(path.as_ref()) {
+            Ok(file) => file,
+            Err(e) => return Err(format!("could not open file {}: {}", path.as_ref().display(), e)),
+        };
+        let map = match Mmap::map(&file) {
+            Ok(map) => map,
+            Err(e) => return Err(format!("could not map file {}: {}", path.as_ref().display(), e)),
+        };
+        Self::from_mapped_file(file, MmapStorage::ReadOnly(map))
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub fn cap(&self) -> usize {
+        BitProto::calc_bitwise_count_from_block_count(self.proto, self.true_cap)
+    }
+
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        self.proto
+    }
+
+    #[inline(always)]
+    pub fn is_writable(&self) -> bool {
+        matches!(self.map, MmapStorage::Writable(_))
+    }
+
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Result<usize, String> {
+        match idx < self.len {
+            true => Ok(unsafe {self.get_unchecked(idx)}),
+            false => Err(format!("index out of bounds for get: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> usize {
+        let idx_proxy = BitProto::idx_proxy(self.proto, idx);
+        idx_proxy.read_val(self.blocks_ptr().as_ptr())
+    }
+
+    #[inline(always)]
+    pub fn view(&self) -> BitVecView<'_> {
+        unsafe {BitVecView::from_raw_parts(self.proto, self.blocks_ptr(), self.len)}
+    }
+
+    #[inline(always)]
+    pub fn slice(&self, idx_range: Range<usize>) -> Result<BitVecView<'_>, String> {
+        self.view().slice(idx_range)
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> BitVecViewIter<'_> {
+        self.view().iter()
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, idx: usize, val: usize) -> Result<(), String> {
+        self.check_writable()?;
+        BitProto::check_value(self.proto, val)?;
+        if idx >= self.len {
+            return Err(format!("index out of bounds for set: (idx) {} >= {} (len)", idx, self.len));
+        }
+        unsafe {self.set_unchecked(idx, val)};
+        Ok(())
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`, `val` must fit in the bit width of the [`BitProto`], and the map must be writable
+    #[inline(always)]
+    pub unsafe fn set_unchecked(&mut self, idx: usize, val: usize) {
+        let idx_proxy = BitProto::idx_proxy(self.proto, idx);
+        idx_proxy.write_val(self.blocks_ptr().as_ptr(), val)
+    }
+
+    #[inline(always)]
+    pub fn replace(&mut self, idx: usize, val: usize) -> Result<usize, String> {
+        self.check_writable()?;
+        BitProto::check_value(self.proto, val)?;
+        match idx < self.len {
+            true => Ok(unsafe {self.replace_unchecked(idx, val)}),
+            false => Err(format!("index out of bounds for replace: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`, `val` must fit in the bit width of the [`BitProto`], and the map must be writable
+    #[inline(always)]
+    pub unsafe fn replace_unchecked(&mut self, idx: usize, val: usize) -> usize {
+        let idx_proxy = BitProto::idx_proxy(self.proto, idx);
+        idx_proxy.replace_val(self.blocks_ptr().as_ptr(), val)
+    }
+
+    pub fn push(&mut self, val: usize) -> Result<(), String> {
+        self.check_writable()?;
+        BitProto::check_value(self.proto, val)?;
+        if self.len == self.proto.MAX_CAPACITY {
+            return Err(format!("BitVec is at maximum capacity ({})", self.proto.MAX_CAPACITY));
+        }
+        self.handle_grow_if_needed(self.len + 1, true)?;
+        let len_proxy = BitProto::idx_proxy(self.proto, self.len);
+        unsafe {len_proxy.write_val(self.blocks_ptr().as_ptr(), val)};
+        self.len += 1;
+        self.write_header();
+        Ok(())
+    }
+
+    pub fn append_iter<II, TO, ESI>(&mut self, source: II) -> Result<(), String>
+    where II: IntoIterator<Item = TO, IntoIter = ESI>, TO: ToOwned<Owned = usize>, ESI: ExactSizeIterator + Iterator<Item = TO> {
+        self.check_writable()?;
+        let iter = source.into_iter();
+        let iter_len = iter.len();
+        if self.proto.MAX_CAPACITY.checked_sub(iter_len).is_none_or(|max_len| max_len < self.len) {
+            return Err(format!("BitVec cannot hold {} more elements, {} elements would reach the maximum capacity ({})", iter_len, self.proto.MAX_CAPACITY - self.len, self.proto.MAX_CAPACITY));
+        }
+        let mut valid_values = Vec::with_capacity(iter_len);
+        for to_val in iter {
+            let val = to_val.to_owned();
+            BitProto::check_value(self.proto, val)?;
+            valid_values.push(val);
+        }
+        self.handle_grow_if_needed(self.len + iter_len, true)?;
+        let blocks_ptr = self.blocks_ptr().as_ptr();
+        for val in valid_values {
+            let len_proxy = BitProto::idx_proxy(self.proto, self.len);
+            unsafe {len_proxy.write_val(blocks_ptr, val)};
+            self.len += 1;
+        }
+        self.write_header();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn grow_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+        self.check_writable()?;
+        self.handle_grow_if_needed(total_elements, true)
+    }
+
+    #[inline]
+    pub fn grow_exact_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+        self.check_writable()?;
+        self.handle_grow_if_needed(total_elements, false)
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+        self.check_writable()?;
+        match self.len.checked_add(extra_elements) {
+            Some(total_elements) => self.handle_grow_if_needed(total_elements, true),
+            None => Err(format!("BitVec cannot hold {} more elements, maximum capacity is {}", extra_elements, self.proto.MAX_CAPACITY)),
+        }
+    }
+
+    #[inline]
+    pub fn first(&self) -> Result<usize, String> {
+        match self.len {
+            0 => Err(String::from("no elements in BitVec to get first")),
+            _ => Ok(unsafe {self.get_unchecked(0)})
+        }
+    }
+
+    #[inline]
+    pub fn last(&self) -> Result<usize, String> {
+        match self.len {
+            0 => Err(String::from("no elements in BitVec to get last")),
+            _ => Ok(unsafe {self.get_unchecked(self.len - 1)})
+        }
+    }
+
+    /// Shorten the `BitVec` to `new_len` elements, the file keeps its size (does nothing if `new_len >= self.len()`)
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) -> Result<(), String> {
+        self.check_writable()?;
+        if new_len < self.len {
+            self.len = new_len;
+            self.write_header();
+        }
+        Ok(())
+    }
+
+    /// Grow or shrink the `BitVec` to `new_len` elements, setting every new element to `val`
+    pub fn resize(&mut self, new_len: usize, val: usize) -> Result<(), String> {
+        self.check_writable()?;
+        BitProto::check_value(self.proto, val)?;
+        if new_len <= self.len {
+            return self.truncate(new_len);
+        }
+        self.handle_grow_if_needed(new_len, true)?;
+        let old_len = self.len;
+        self.len = new_len;
+        unsafe {self.fill_range_unchecked(old_len..new_len, val)};
+        self.write_header();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn fill(&mut self, val: usize) -> Result<(), String> {
+        self.fill_range(0..self.len, val)
+    }
+
+    pub fn fill_range(&mut self, range: Range<usize>, val: usize) -> Result<(), String> {
+        self.check_writable()?;
+        if range.start > range.end || range.end > self.len {
+            return Err(format!("index out of bounds for fill_range: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", range.start, range.end, range.end, self.len));
+        }
+        BitProto::check_value(self.proto, val)?;
+        unsafe {self.fill_range_unchecked(range, val)};
+        Ok(())
+    }
+
+    /// Append a copy of the elements in `src_range` to the end of the `BitVec`
+    pub fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+        self.check_writable()?;
+        if src_range.start > src_range.end || src_range.end > self.len {
+            return Err(format!("index out of bounds for extend_from_within: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", src_range.start, src_range.end, src_range.end, self.len));
+        }
+        let count = src_range.len();
+        if self.proto.MAX_CAPACITY - count < self.len {
+            return Err(format!("BitVec cannot hold {} more elements, {} elements would reach the maximum capacity ({})", count, self.proto.MAX_CAPACITY - self.len, self.proto.MAX_CAPACITY));
+        }
+        self.handle_grow_if_needed(self.len + count, true)?;
+        for idx in src_range {
+            let val = unsafe {self.get_unchecked(idx)};
+            self.len += 1;
+            unsafe {self.set_unchecked(self.len - 1, val)};
+        }
+        self.write_header();
+        Ok(())
+    }
+
+    /// Move the elements from `at` onward into a new heap allocated [`LProtoBitVec`], leaving `0..at` in the file
+    pub fn split_off(&mut self, at: usize) -> Result<LProtoBitVec, String> {
+        self.check_writable()?;
+        if at > self.len {
+            return Err(format!("index out of bounds for split_off: (idx) {} > {} (len)", at, self.len));
+        }
+        let mut tail = LProtoBitVec::new(self.proto);
+        tail.append_iter(self.view().slice(at..self.len)?.iter())?;
+        self.truncate(at)?;
+        Ok(tail)
+    }
+
+    /// Reverse the order of the elements in place
+    #[inline]
+    pub fn reverse(&mut self) -> Result<(), String> {
+        self.check_writable()?;
+        unsafe {self.reverse_range_unchecked(0..self.len)};
+        Ok(())
+    }
+
+    pub fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        self.check_writable()?;
+        if mid > self.len {
+            return Err(format!("index out of bounds for rotate_left: (mid) {} > {} (len)", mid, self.len));
+        }
+        unsafe {
+            self.reverse_range_unchecked(0..mid);
+            self.reverse_range_unchecked(mid..self.len);
+            self.reverse_range_unchecked(0..self.len);
+        }
+        Ok(())
+    }
+
+    pub fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        self.check_writable()?;
+        if count > self.len {
+            return Err(format!("index out of bounds for rotate_right: (count) {} > {} (len)", count, self.len));
+        }
+        self.rotate_left(self.len - count)
+    }
+
+    /// Synchronously write all modified pages of a writable map back to the file
+    #[inline]
+    pub fn flush(&self) -> Result<(), String> {
+        match &self.map {
+            MmapStorage::ReadOnly(_) => Ok(()),
+            MmapStorage::Writable(map) => match map.flush() {
+                Ok(()) => Ok(()),
+                Err(e) => Err(format!("could not flush mapped file: {}", e)),
+            }
+        }
+    }
+
+    unsafe fn from_mapped_file(file: File, map: MmapStorage) -> Result<Self, String> {
+        let bytes: &[u8] = match &map {
+            MmapStorage::ReadOnly(map) => map,
+            MmapStorage::Writable(map) => map,
+        };
+        if bytes.len() < Self::HEADER_SIZE {
+            return Err(format!("file is too small to contain a BitVec header: (file size) {} < {} (header size)", bytes.len(), Self::HEADER_SIZE));
+        }
+        let bit_width = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+        let block_bits = u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let len = u64::from_ne_bytes([bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]]);
+        if block_bits != usize::BITS {
+            return Err(format!("file was written with {}-bit blocks, but this machine uses {}-bit blocks", block_bits, usize::BITS));
+        }
+        if bit_width == 0 || bit_width > usize::BITS as usize {
+            return Err(format!("file header has an invalid bit width: {}", bit_width));
+        }
+        let growth_kind = u32::from_ne_bytes([bytes[16], bytes[17], bytes[18], bytes[19]]);
+        let growth_param = u64::from_ne_bytes([bytes[24], bytes[25], bytes[26], bytes[27], bytes[28], bytes[29], bytes[30], bytes[31]]);
+        let growth = match (growth_kind, usize::try_from(growth_param)) {
+            (0, _) => GrowthPolicy::Double,
+            (1, _) => GrowthPolicy::OneAndHalf,
+            (2, Ok(step)) => GrowthPolicy::FixedStep(step),
+            (3, Ok(page_size)) if page_size != 0 => GrowthPolicy::PageAligned(page_size),
+            _ => return Err(format!("file header has an invalid growth policy: (kind) {}, (parameter) {}", growth_kind, growth_param)),
+        };
+        let proto = BitProto::create(bit_width).with_growth(growth);
+        let true_cap = (bytes.len() - Self::HEADER_SIZE) / size_of::<usize>();
+        let cap = BitProto::calc_bitwise_count_from_block_count(proto, true_cap);
+        if len > cap as u64 {
+            return Err(format!("file header length is larger than the data in the file: (len) {} > {} (cap)", len, cap));
+        }
+        Ok(Self {
+            proto,
+            file,
+            map,
+            len: len as usize,
+            true_cap
+        })
+    }
+
+    #[inline(always)]
+    fn blocks_ptr(&self) -> NonNull<usize> {
+        let map_ptr = match &self.map {
+            MmapStorage::ReadOnly(map) => map.as_ptr(),
+            MmapStorage::Writable(map) => map.as_ptr(),
+        };
+        unsafe {NonNull::new_unchecked(map_ptr.add(Self::HEADER_SIZE) as *mut usize)}
+    }
+
+    /// ## Safety
+    /// `range` must be inside `0..self.len()`, `val` must fit in the bit width of the [`BitProto`], and the map must be writable
+    unsafe fn fill_range_unchecked(&mut self, range: Range<usize>, val: usize) {
+        for idx in range {
+            self.set_unchecked(idx, val);
+        }
+    }
+
+    /// ## Safety
+    /// `range` must be inside `0..self.len()` and the map must be writable
+    unsafe fn reverse_range_unchecked(&mut self, range: Range<usize>) {
+        if range.len() < 2 {
+            return;
+        }
+        let mut low_idx = range.start;
+        let mut high_idx = range.end - 1;
+        while low_idx < high_idx {
+            let low_val = self.replace_unchecked(low_idx, self.get_unchecked(high_idx));
+            self.set_unchecked(high_idx, low_val);
+            low_idx += 1;
+            high_idx -= 1;
+        }
+    }
+
+    #[inline(always)]
+    fn check_writable(&self) -> Result<(), String> {
+        match self.is_writable() {
+            true => Ok(()),
+            false => Err(String::from("cannot modify a BitVec mapped as read-only")),
+        }
+    }
+
+    fn write_header(&mut self) {
+        if let MmapStorage::Writable(map) = &mut self.map {
+            map[0..4].copy_from_slice(&(self.proto.BITS as u32).to_ne_bytes());
+            map[4..8].copy_from_slice(&usize::BITS.to_ne_bytes());
+            map[8..16].copy_from_slice(&(self.len as u64).to_ne_bytes());
+            let (growth_kind, growth_param) = match self.proto.GROWTH {
+                GrowthPolicy::Double => (0u32, 0usize),
+                GrowthPolicy::OneAndHalf => (1, 0),
+                GrowthPolicy::FixedStep(step) => (2, step),
+                GrowthPolicy::PageAligned(page_size) => (3, page_size),
+            };
+            map[16..20].copy_from_slice(&growth_kind.to_ne_bytes());
+            map[20..24].copy_from_slice(&0u32.to_ne_bytes());
+            map[24..32].copy_from_slice(&(growth_param as u64).to_ne_bytes());
+        }
+    }
+
+    fn set_file_len(file: &File, true_cap: usize) -> Result<(), String> {
+        let file_len = match true_cap.checked_mul(size_of::<usize>()).and_then(|data_len| data_len.checked_add(Self::HEADER_SIZE)) {
+            Some(file_len) => file_len as u64,
+            None => return Err(format!("mapped file for {} blocks would exceed usize::MAX bytes", true_cap)),
+        };
+        match file.set_len(file_len) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(format!("could not resize mapped file to {} bytes: {}", file_len, e)),
+        }
+    }
+
+    fn handle_grow_if_needed(&mut self, min_capacity: usize, grow_exponential: bool) -> Result<(), String> {
+        if min_capacity > self.proto.MAX_CAPACITY {
+            return Err(format!("BitVec cannot hold {} elements, maximum capacity is {}", min_capacity, self.proto.MAX_CAPACITY));
+        }
+        let true_min_capacity = BitProto::calc_block_count_from_bitwise_count(self.proto, min_capacity);
+        if true_min_capacity > self.true_cap {
+            let new_true_cap = match grow_exponential {
+                true => BitProto::calc_grown_block_count(self.proto, true_min_capacity),
+                false => true_min_capacity,
+            };
+            Self::set_file_len(&self.file, new_true_cap)?;
+            let new_map = match unsafe {MmapMut::map_mut(&self.file)} {
+                Ok(map) => map,
+                Err(e) => return Err(format!("could not remap file after growing: {}", e)),
+            };
+            self.map = MmapStorage::Writable(new_map);
+            self.true_cap = new_true_cap;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,468 @@
+use std::slice;
+
+use rayon::iter::{
+    IndexedParallelIterator,
+    ParallelIterator,
+    Map,
+    plumbing::{
+        bridge,
+        Consumer,
+        Producer,
+        ProducerCallback,
+        UnindexedConsumer
+    },
+};
+use rayon::slice::ParallelSliceMut;
+
+use crate::{
+    BitProto,
+    BitVecView,
+    BitVecViewIter,
+    CProtoBitVec,
+    ConstGrowth,
+    LProtoBitVec,
+    RawBitVec,
+    SProtoBitVec,
+    TypedBitElem,
+    TypedBitVec,
+    TypedBitVecView,
+    NonNull,
+    PhantomData,
+};
+
+/// Rough number of blocks each worker is handed by the parallel bulk operations
+const PAR_WORK_BLOCKS: usize = 512;
+
+#[inline(always)]
+fn par_work_len(proto: BitProto) -> usize {
+    let period = BitProto::block_aligned_period(proto);
+    let period_blocks = BitProto::calc_block_count_from_bitwise_count(proto, period);
+    period * (PAR_WORK_BLOCKS / period_blocks).max(1)
+}
+
+/// ## `BitVecChunkMut`: "Bitwise Vector Mutable Chunk"
+/// An exclusive, fixed-length window into a run of elements handed out by `par_chunks_mut()`
+///
+/// Every chunk starts on a `usize` block boundary and owns all the blocks it touches, so chunks can be written
+/// from different threads without ever sharing a block
+pub struct BitVecChunkMut<'chunk> {
+    pub(crate) proto: BitProto,
+    pub(crate) ptr: NonNull<usize>,
+    pub(crate) len: usize,
+    pub(crate) _borrow: PhantomData<&'chunk mut [usize]>,
+}
+
+// SAFETY: a BitVecChunkMut is the only handle to its blocks for `'chunk`, exactly like a `&'chunk mut [usize]`
+unsafe impl<'chunk> Send for BitVecChunkMut<'chunk> {}
+unsafe impl<'chunk> Sync for BitVecChunkMut<'chunk> {}
+
+impl<'chunk> BitVecChunkMut<'chunk> {
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Result<usize, String> {
+        match idx < self.len {
+            true => Ok(unsafe {self.get_unchecked(idx)}),
+            false => Err(format!("index out of bounds for get: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> usize {
+        BitProto::idx_proxy(self.proto, idx).read_val(self.ptr.as_ptr())
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, idx: usize, val: usize) -> Result<(), String> {
+        BitProto::check_value(self.proto, val)?;
+        match idx < self.len {
+            true => {
+                unsafe {self.set_unchecked(idx, val)};
+                Ok(())
+            },
+            false => Err(format!("index out of bounds for set: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()` and `val` must fit in the chunk's bit width
+    #[inline(always)]
+    pub unsafe fn set_unchecked(&mut self, idx: usize, val: usize) {
+        BitProto::idx_proxy(self.proto, idx).write_val(self.ptr.as_ptr(), val)
+    }
+
+    #[inline(always)]
+    pub fn replace(&mut self, idx: usize, val: usize) -> Result<usize, String> {
+        BitProto::check_value(self.proto, val)?;
+        match idx < self.len {
+            true => Ok(unsafe {self.replace_unchecked(idx, val)}),
+            false => Err(format!("index out of bounds for replace: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()` and `val` must fit in the chunk's bit width
+    #[inline(always)]
+    pub unsafe fn replace_unchecked(&mut self, idx: usize, val: usize) -> usize {
+        BitProto::idx_proxy(self.proto, idx).replace_val(self.ptr.as_ptr(), val)
+    }
+
+    #[inline(always)]
+    pub fn view(&self) -> BitVecView<'_> {
+        unsafe {BitVecView::from_raw_parts(self.proto, self.ptr, self.len)}
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> BitVecViewIter<'_> {
+        self.view().into_iter()
+    }
+}
+
+/// ## `TypedBitVecChunkMut`: "Typed Bitwise Vector Mutable Chunk"
+/// A [`BitVecChunkMut`] that converts elements to and from `T::Base`
+pub struct TypedBitVecChunkMut<'chunk, T: TypedBitElem>(pub(crate) BitVecChunkMut<'chunk>, pub(crate) PhantomData<T>);
+
+impl<'chunk, T: TypedBitElem> TypedBitVecChunkMut<'chunk, T> {
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Result<T::Base, String> {
+        Ok(T::bits_to_val(self.0.get(idx)?))
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> T::Base {
+        T::bits_to_val(self.0.get_unchecked(idx))
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, idx: usize, val: T::Base) -> Result<(), String> {
+        self.0.set(idx, T::try_val_to_bits(val)?)
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn set_unchecked(&mut self, idx: usize, val: T::Base) {
+        self.0.set_unchecked(idx, T::val_to_bits(val))
+    }
+
+    #[inline(always)]
+    pub fn replace(&mut self, idx: usize, val: T::Base) -> Result<T::Base, String> {
+        Ok(T::bits_to_val(self.0.replace(idx, T::try_val_to_bits(val)?)?))
+    }
+
+    #[inline(always)]
+    pub fn view(&self) -> TypedBitVecView<'_, T> {
+        TypedBitVecView(self.0.view(), PhantomData)
+    }
+}
+
+/// A parallel iterator over the elements of a [`BitVecView`]
+///
+/// Splitting never copies or re-reads data, each half simply narrows the borrowed range of elements
+pub struct BitVecViewParIter<'view>(pub(crate) BitVecView<'view>);
+
+impl<'view> ParallelIterator for BitVecViewParIter<'view> {
+    type Item = usize;
+
+    #[inline(always)]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where C: UnindexedConsumer<Self::Item> {
+        bridge(self, consumer)
+    }
+
+    #[inline(always)]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+impl<'view> IndexedParallelIterator for BitVecViewParIter<'view> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where C: Consumer<Self::Item> {
+        bridge(self, consumer)
+    }
+
+    #[inline(always)]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where CB: ProducerCallback<Self::Item> {
+        callback.callback(BitVecViewProducer(self.0))
+    }
+}
+
+struct BitVecViewProducer<'view>(BitVecView<'view>);
+
+impl<'view> Producer for BitVecViewProducer<'view> {
+    type Item = usize;
+
+    type IntoIter = BitVecViewIter<'view>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+
+    #[inline(always)]
+    fn split_at(self, idx: usize) -> (Self, Self) {
+        let left = BitVecView { len: idx, ..self.0.clone() };
+        let right = BitVecView { start: self.0.start + idx, len: self.0.len - idx, ..self.0 };
+        (BitVecViewProducer(left), BitVecViewProducer(right))
+    }
+}
+
+pub type TypedBitVecViewParIter<'view, T> = Map<BitVecViewParIter<'view>, fn(usize) -> <T as TypedBitElem>::Base>;
+
+impl<'view> BitVecView<'view> {
+    #[inline(always)]
+    pub fn par_iter(&self) -> BitVecViewParIter<'view> {
+        BitVecViewParIter(self.clone())
+    }
+}
+
+impl<'view, T: TypedBitElem> TypedBitVecView<'view, T>
+where T::Base: Send {
+    #[inline(always)]
+    pub fn par_iter(&self) -> TypedBitVecViewParIter<'view, T> {
+        self.0.par_iter().map(T::bits_to_val as fn(usize) -> T::Base)
+    }
+}
+
+impl RawBitVec {
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`]
+    #[inline]
+    pub unsafe fn par_iter(&self, proto: BitProto) -> BitVecViewParIter<'_> {
+        self.view(proto).par_iter()
+    }
+
+    /// Split the vector into chunks of `chunk_len` elements (the last chunk may be shorter) that can be mutated in parallel
+    ///
+    /// `chunk_len` must be a non-zero multiple of [`BitProto::block_aligned_period()`] so that every chunk starts on a
+    /// block boundary
+    ///
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`]
+    #[inline]
+    pub unsafe fn par_chunks_mut(&mut self, proto: BitProto, chunk_len: usize) -> Result<impl IndexedParallelIterator<Item = BitVecChunkMut<'_>>, String> {
+        let period = BitProto::block_aligned_period(proto);
+        if chunk_len == 0 || !chunk_len.is_multiple_of(period) {
+            return Err(format!("chunk length for par_chunks_mut must be a non-zero multiple of the block-aligned period: (chunk len) {} % {} (period) != 0", chunk_len, period));
+        }
+        let total_len = self.len;
+        let period_blocks = BitProto::calc_block_count_from_bitwise_count(proto, period);
+        let chunk_blocks = (chunk_len / period).saturating_mul(period_blocks);
+        let used_blocks = BitProto::calc_block_count_from_bitwise_count(proto, total_len);
+        let blocks = slice::from_raw_parts_mut(self.ptr.as_ptr(), used_blocks);
+        Ok(blocks.par_chunks_mut(chunk_blocks).enumerate().map(move |(chunk_idx, chunk)| {
+            BitVecChunkMut {
+                proto,
+                ptr: NonNull::from(chunk).cast(),
+                len: chunk_len.min(total_len - (chunk_idx * chunk_len)),
+                _borrow: PhantomData
+            }
+        }))
+    }
+
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`]
+    #[inline]
+    pub unsafe fn par_fill(&mut self, proto: BitProto, val: usize) -> Result<(), String> {
+        BitProto::check_value(proto, val)?;
+        self.par_chunks_mut(proto, par_work_len(proto))?.for_each(|mut chunk| {
+            for idx in 0..chunk.len {
+                chunk.set_unchecked(idx, val);
+            }
+        });
+        Ok(())
+    }
+
+    /// Replace every element with the result of `map_fn` in parallel
+    ///
+    /// If `map_fn` returns a value that does not fit in the bit width an error is returned, and the elements
+    /// processed before the error was noticed keep their new values
+    ///
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`]
+    #[inline]
+    pub unsafe fn par_map<F>(&mut self, proto: BitProto, map_fn: F) -> Result<(), String>
+    where F: Fn(usize) -> usize + Sync + Send {
+        self.par_try_map(proto, |val| {
+            let new_val = map_fn(val);
+            BitProto::check_value(proto, new_val)?;
+            Ok(new_val)
+        })
+    }
+
+    /// Same as [`RawBitVec::par_map()`], but `map_fn` does its own checking and returns the new value's bits or an error
+    ///
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`], and every `Ok` value
+    /// returned by `map_fn` must fit in `proto.BITS` bits
+    #[inline]
+    pub(crate) unsafe fn par_try_map<F>(&mut self, proto: BitProto, map_fn: F) -> Result<(), String>
+    where F: Fn(usize) -> Result<usize, String> + Sync + Send {
+        self.par_chunks_mut(proto, par_work_len(proto))?.try_for_each(|mut chunk| {
+            for idx in 0..chunk.len {
+                let new_val = map_fn(chunk.get_unchecked(idx))?;
+                chunk.set_unchecked(idx, new_val);
+            }
+            Ok(())
+        })
+    }
+
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`]
+    #[inline]
+    pub unsafe fn par_count<F>(&self, proto: BitProto, predicate: F) -> usize
+    where F: Fn(usize) -> bool + Sync + Send {
+        self.par_iter(proto).filter(|val| predicate(*val)).count()
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> CProtoBitVec<BIT_WIDTH, G> {
+    #[inline(always)]
+    pub fn par_iter(&self) -> BitVecViewParIter<'_> {
+        unsafe {self.0.par_iter(Self::PROTO)}
+    }
+
+    #[inline(always)]
+    pub fn par_chunks_mut(&mut self, chunk_len: usize) -> Result<impl IndexedParallelIterator<Item = BitVecChunkMut<'_>>, String> {
+        unsafe {self.0.par_chunks_mut(Self::PROTO, chunk_len)}
+    }
+
+    #[inline(always)]
+    pub fn par_fill(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.0.par_fill(Self::PROTO, val)}
+    }
+
+    #[inline(always)]
+    pub fn par_map<F>(&mut self, map_fn: F) -> Result<(), String>
+    where F: Fn(usize) -> usize + Sync + Send {
+        unsafe {self.0.par_map(Self::PROTO, map_fn)}
+    }
+
+    #[inline(always)]
+    pub fn par_count<F>(&self, predicate: F) -> usize
+    where F: Fn(usize) -> bool + Sync + Send {
+        unsafe {self.0.par_count(Self::PROTO, predicate)}
+    }
+}
+
+impl SProtoBitVec {
+    #[inline(always)]
+    pub fn par_iter(&self) -> BitVecViewParIter<'_> {
+        unsafe {self.vec.par_iter(*self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn par_chunks_mut(&mut self, chunk_len: usize) -> Result<impl IndexedParallelIterator<Item = BitVecChunkMut<'_>>, String> {
+        unsafe {self.vec.par_chunks_mut(*self.proto, chunk_len)}
+    }
+
+    #[inline(always)]
+    pub fn par_fill(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.vec.par_fill(*self.proto, val)}
+    }
+
+    #[inline(always)]
+    pub fn par_map<F>(&mut self, map_fn: F) -> Result<(), String>
+    where F: Fn(usize) -> usize + Sync + Send {
+        unsafe {self.vec.par_map(*self.proto, map_fn)}
+    }
+
+    #[inline(always)]
+    pub fn par_count<F>(&self, predicate: F) -> usize
+    where F: Fn(usize) -> bool + Sync + Send {
+        unsafe {self.vec.par_count(*self.proto, predicate)}
+    }
+}
+
+impl LProtoBitVec {
+    #[inline(always)]
+    pub fn par_iter(&self) -> BitVecViewParIter<'_> {
+        unsafe {self.vec.par_iter(self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn par_chunks_mut(&mut self, chunk_len: usize) -> Result<impl IndexedParallelIterator<Item = BitVecChunkMut<'_>>, String> {
+        unsafe {self.vec.par_chunks_mut(self.proto, chunk_len)}
+    }
+
+    #[inline(always)]
+    pub fn par_fill(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.vec.par_fill(self.proto, val)}
+    }
+
+    #[inline(always)]
+    pub fn par_map<F>(&mut self, map_fn: F) -> Result<(), String>
+    where F: Fn(usize) -> usize + Sync + Send {
+        unsafe {self.vec.par_map(self.proto, map_fn)}
+    }
+
+    #[inline(always)]
+    pub fn par_count<F>(&self, predicate: F) -> usize
+    where F: Fn(usize) -> bool + Sync + Send {
+        unsafe {self.vec.par_count(self.proto, predicate)}
+    }
+}
+
+impl<T: TypedBitElem> TypedBitVec<T> {
+    #[inline(always)]
+    pub fn par_iter(&self) -> TypedBitVecViewParIter<'_, T>
+    where T::Base: Send {
+        self.view().par_iter()
+    }
+
+    #[inline(always)]
+    pub fn par_chunks_mut(&mut self, chunk_len: usize) -> Result<impl IndexedParallelIterator<Item = TypedBitVecChunkMut<'_, T>>, String>
+    where T: Send + Sync {
+        let chunks = unsafe {self.0.par_chunks_mut(T::PROTO, chunk_len)?};
+        Ok(chunks.map(|chunk| TypedBitVecChunkMut(chunk, PhantomData)))
+    }
+
+    #[inline(always)]
+    pub fn par_fill(&mut self, val: T::Base) -> Result<(), String> {
+        unsafe {self.0.par_fill(T::PROTO, T::try_val_to_bits(val)?)}
+    }
+
+    /// If `map_fn` returns a value [`TypedBitElem::try_val_to_bits()`] rejects, its error is returned and the elements
+    /// processed before the error was noticed keep their new values
+    #[inline(always)]
+    pub fn par_map<F>(&mut self, map_fn: F) -> Result<(), String>
+    where F: Fn(T::Base) -> T::Base + Sync + Send {
+        unsafe {self.0.par_try_map(T::PROTO, |bits| T::try_val_to_bits(map_fn(T::bits_to_val(bits))))}
+    }
+
+    #[inline(always)]
+    pub fn par_count<F>(&self, predicate: F) -> usize
+    where F: Fn(T::Base) -> bool + Sync + Send {
+        unsafe {self.0.par_count(T::PROTO, |bits| predicate(T::bits_to_val(bits)))}
+    }
+}
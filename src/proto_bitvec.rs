@@ -0,0 +1,434 @@
+use std::ops::RangeFrom;
+
+use crate::{
+    PackedUint,
+    BitProto,
+    BitVecLike,
+    BitVecView,
+    BitVecViewChunks,
+    BitVecViewChunksExact,
+    BitVecViewRChunks,
+    BitVecViewWindows,
+    BitVecViewBlockChunks,
+    BitVecViewIter,
+    CProtoBitVec,
+    ConstGrowth,
+    LProtoBitVec,
+    LProtoBitVecIter,
+    RawBitVec,
+    SProtoBitVec,
+    Range,
+    ManuallyDrop
+};
+
+/// Anything that can hand out the [`BitProto`] for a [`ProtoBitVec`]
+///
+/// Implemented for [`ConstProto<BIT_WIDTH>`] (a zero-sized, monomorphized constant), [`BitProto`] (a local copy) and
+/// `&'static BitProto` (a static reference), mirroring [`CProtoBitVec`](crate::CProtoBitVec),
+/// [`LProtoBitVec`](crate::LProtoBitVec) and [`SProtoBitVec`](crate::SProtoBitVec) respectively
+pub trait ProtoSource: Copy {
+    fn proto(&self) -> BitProto;
+}
+
+/// A zero-sized [`ProtoSource`] whose [`BitProto`] is a monomorphized constant for every separate `<BIT_WIDTH>`
+///
+/// Its [`BitProto`] always uses the default [`GrowthPolicy`](crate::GrowthPolicy), so a [`CProtoBitVec`] with another
+/// [`ConstGrowth`] converted into a `ProtoBitVec<ConstProto<BIT_WIDTH>>` keeps its elements but grows with the default
+/// policy until it is converted back
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConstProto<const BIT_WIDTH: usize>;
+
+impl<const BIT_WIDTH: usize> ConstProto<BIT_WIDTH> {
+    pub const PROTO: BitProto = BitProto::create(BIT_WIDTH);
+}
+
+impl<const BIT_WIDTH: usize> ProtoSource for ConstProto<BIT_WIDTH> {
+    #[inline(always)]
+    fn proto(&self) -> BitProto {
+        Self::PROTO
+    }
+}
+
+impl ProtoSource for BitProto {
+    #[inline(always)]
+    fn proto(&self) -> BitProto {
+        *self
+    }
+}
+
+impl ProtoSource for &'static BitProto {
+    #[inline(always)]
+    fn proto(&self) -> BitProto {
+        **self
+    }
+}
+
+/// ## `ProtoBitVec`: "Prototype Source Bitwise Vector"
+/// A `BitVec` generic over where its [`BitProto`] comes from, see [`ProtoSource`]
+///
+/// This is a thin wrapper around [`RawBitVec`] whose API is provided entirely through [`BitVecLike`]. It lets code pick
+/// the storage strategy of [`CProtoBitVec`](crate::CProtoBitVec), [`SProtoBitVec`](crate::SProtoBitVec) or
+/// [`LProtoBitVec`](crate::LProtoBitVec) with a single type parameter
+///
+/// ### Pros
+/// - One implementation for all three ways of storing a [`BitProto`]
+/// - Same size and constant-propogation as the matching concrete wrapper
+///
+/// ### Cons
+/// - [`BitVecLike`] must be in scope to call most methods
+pub struct ProtoBitVec<P: ProtoSource> {
+    pub(crate) source: P,
+    pub(crate) vec: RawBitVec
+}
+
+impl<P: ProtoSource> ProtoBitVec<P> {
+    #[inline(always)]
+    pub fn new(source: P) -> Self {
+        Self { source, vec: RawBitVec::new() }
+    }
+
+    #[inline(always)]
+    pub fn with_capacity(source: P, cap: usize) -> Self {
+        Self { source, vec: RawBitVec::with_capacity(source.proto(), cap) }
+    }
+
+    #[inline(always)]
+    pub fn from_elem(source: P, val: usize, count: usize) -> Result<Self, String> {
+        Ok(Self { source, vec: unsafe {RawBitVec::from_elem(source.proto(), val, count)?} })
+    }
+
+    #[inline(always)]
+    pub fn source(&self) -> P {
+        self.source
+    }
+
+    #[inline(always)]
+    pub fn view(&self) -> BitVecView<'_> {
+        unsafe {self.vec.view(self.source.proto())}
+    }
+
+    #[inline(always)]
+    pub fn chunks(&self, chunk_len: usize) -> Result<BitVecViewChunks<'_>, String> {
+        self.view().chunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn chunks_exact(&self, chunk_len: usize) -> Result<BitVecViewChunksExact<'_>, String> {
+        self.view().chunks_exact(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn rchunks(&self, chunk_len: usize) -> Result<BitVecViewRChunks<'_>, String> {
+        self.view().rchunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn windows(&self, window_len: usize) -> Result<BitVecViewWindows<'_>, String> {
+        self.view().windows(window_len)
+    }
+
+    #[inline(always)]
+    pub fn block_chunks(&self) -> BitVecViewBlockChunks<'_> {
+        self.view().block_chunks()
+    }
+
+    #[inline(always)]
+    pub fn unpack_into<U: PackedUint>(&self, range: Range<usize>, out: &mut [U]) -> Result<(), String> {
+        unsafe {self.vec.unpack_into(self.source.proto(), range, out)}
+    }
+
+    #[inline(always)]
+    pub fn pack_from<U: PackedUint>(&mut self, idx: usize, src: &[U]) -> Result<(), String> {
+        unsafe {self.vec.pack_from(self.source.proto(), idx, src)}
+    }
+
+    /// ## Safety
+    /// The returned [`RawBitVec`] must only ever be used with `self.source().proto()`
+    #[inline(always)]
+    pub unsafe fn into_raw(self) -> RawBitVec {
+        let nodrop_self = ManuallyDrop::new(self);
+        RawBitVec {
+            ptr: nodrop_self.vec.ptr,
+            len: nodrop_self.vec.len,
+            true_cap: nodrop_self.vec.true_cap
+        }
+    }
+
+    /// ## Safety
+    /// `raw` must have only ever been used with a [`BitProto`] of the same bit width as `source.proto()`
+    #[inline(always)]
+    pub unsafe fn from_raw(raw: RawBitVec, source: P) -> Self {
+        Self { source, vec: raw }
+    }
+}
+
+impl<P: ProtoSource> BitVecLike for ProtoBitVec<P> {
+    type Elem = usize;
+    type Iter<'vec> = BitVecViewIter<'vec> where Self: 'vec;
+
+    #[inline(always)]
+    fn proto(&self) -> BitProto {
+        self.source.proto()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.vec.len
+    }
+
+    #[inline(always)]
+    fn cap(&self) -> usize {
+        unsafe {self.vec.cap(self.source.proto())}
+    }
+
+    #[inline(always)]
+    fn free(&self) -> usize {
+        unsafe {self.vec.free(self.source.proto())}
+    }
+
+    #[inline(always)]
+    fn grow_exact_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
+        unsafe {self.vec.grow_exact_for_additional_elements_if_needed(self.source.proto(), extra_elements)}
+    }
+
+    #[inline(always)]
+    fn grow_exact_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+        unsafe {self.vec.grow_exact_for_total_elements_if_needed(self.source.proto(), total_elements)}
+    }
+
+    #[inline(always)]
+    fn grow_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
+        unsafe {self.vec.grow_for_additional_elements_if_needed(self.source.proto(), extra_elements)}
+    }
+
+    #[inline(always)]
+    fn grow_for_total_elements_if_needed(&mut self, total_elements: usize) -> Result<(), String> {
+        unsafe {self.vec.grow_for_total_elements_if_needed(self.source.proto(), total_elements)}
+    }
+
+    #[inline(always)]
+    fn trim_excess_capacity(&mut self, extra_capacity_to_keep: usize) -> Result<(), String> {
+        unsafe {self.vec.trim_excess_capacity(self.source.proto(), extra_capacity_to_keep)}
+    }
+
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.vec.clear()
+    }
+
+    #[inline(always)]
+    fn discard_from_end(&mut self, count: usize) {
+        self.vec.discard_from_end(count)
+    }
+
+    #[inline(always)]
+    fn push(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.vec.push(self.source.proto(), val)}
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Result<usize, String> {
+        unsafe {self.vec.pop(self.source.proto())}
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, idx: usize, val: usize) -> Result<(), String> {
+        unsafe {self.vec.insert(self.source.proto(), idx, val)}
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, idx: usize) -> Result<usize, String> {
+        unsafe {self.vec.remove(self.source.proto(), idx)}
+    }
+
+    #[inline(always)]
+    fn swap(&mut self, idx_a: usize, idx_b: usize) -> Result<(), String> {
+        unsafe {self.vec.swap(self.source.proto(), idx_a, idx_b)}
+    }
+
+    #[inline(always)]
+    fn swap_pop(&mut self, idx: usize) -> Result<usize, String> {
+        unsafe {self.vec.swap_pop(self.source.proto(), idx)}
+    }
+
+    #[inline(always)]
+    fn get(&self, idx: usize) -> Result<usize, String> {
+        unsafe {self.vec.get(self.source.proto(), idx)}
+    }
+
+    #[inline(always)]
+    fn set(&mut self, idx: usize, val: usize) -> Result<(), String> {
+        unsafe {self.vec.set(self.source.proto(), idx, val)}
+    }
+
+    #[inline(always)]
+    fn replace(&mut self, idx: usize, val: usize) -> Result<usize, String> {
+        unsafe {self.vec.replace(self.source.proto(), idx, val)}
+    }
+
+    #[inline(always)]
+    fn insert_iter<II>(&mut self, insert_idx: usize, source: II) -> Result<(), String>
+    where II: IntoIterator<Item = usize>, II::IntoIter: ExactSizeIterator {
+        unsafe {self.vec.insert_iter(self.source.proto(), insert_idx, source)}
+    }
+
+    #[inline(always)]
+    fn append_iter<II>(&mut self, source: II) -> Result<(), String>
+    where II: IntoIterator<Item = usize>, II::IntoIter: ExactSizeIterator {
+        unsafe {self.vec.append_iter(self.source.proto(), source)}
+    }
+
+    #[inline(always)]
+    fn insert_bitvec(&mut self, insert_idx: usize, bitvec: Self) -> Result<(), String> {
+        unsafe {self.vec.insert_bitvec(self.source.proto(), insert_idx, bitvec.into_raw())}
+    }
+
+    #[inline(always)]
+    fn append_bitvec(&mut self, bitvec: Self) -> Result<(), String> {
+        unsafe {self.vec.append_bitvec(self.source.proto(), bitvec.into_raw())}
+    }
+
+    #[inline(always)]
+    fn remove_range(&mut self, idx_range: Range<usize>) -> Result<Self, String> {
+        let raw = unsafe {self.vec.remove_range(self.source.proto(), idx_range)?};
+        Ok(Self { source: self.source, vec: raw })
+    }
+
+    #[inline(always)]
+    fn trim_range(&mut self, idx_range: RangeFrom<usize>) -> Result<Self, String> {
+        let raw = unsafe {self.vec.trim_range(self.source.proto(), idx_range)?};
+        Ok(Self { source: self.source, vec: raw })
+    }
+
+    #[inline(always)]
+    fn truncate(&mut self, new_len: usize) {
+        self.vec.truncate(new_len)
+    }
+
+    #[inline(always)]
+    fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+        unsafe {self.vec.reserve(self.source.proto(), extra_elements)}
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Result<usize, String> {
+        unsafe {self.vec.first(self.source.proto())}
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Result<usize, String> {
+        unsafe {self.vec.last(self.source.proto())}
+    }
+
+    #[inline(always)]
+    fn resize(&mut self, new_len: usize, val: usize) -> Result<(), String> {
+        unsafe {self.vec.resize(self.source.proto(), new_len, val)}
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.vec.fill(self.source.proto(), val)}
+    }
+
+    #[inline(always)]
+    fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+        unsafe {self.vec.extend_from_within(self.source.proto(), src_range)}
+    }
+
+    #[inline(always)]
+    fn split_off(&mut self, at: usize) -> Result<Self, String> {
+        let raw = unsafe {self.vec.split_off(self.source.proto(), at)?};
+        Ok(Self { source: self.source, vec: raw })
+    }
+
+    #[inline(always)]
+    fn reverse(&mut self) {
+        unsafe {self.vec.reverse(self.source.proto())}
+    }
+
+    #[inline(always)]
+    fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        unsafe {self.vec.rotate_left(self.source.proto(), mid)}
+    }
+
+    #[inline(always)]
+    fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        unsafe {self.vec.rotate_right(self.source.proto(), count)}
+    }
+
+    #[inline(always)]
+    fn fill_range(&mut self, range: Range<usize>, val: usize) -> Result<(), String> {
+        unsafe {self.vec.fill_range(self.source.proto(), range, val)}
+    }
+
+    #[inline(always)]
+    fn repeat(&self, count: usize) -> Result<Self, String> {
+        let raw = unsafe {self.vec.repeat(self.source.proto(), count)?};
+        Ok(Self { source: self.source, vec: raw })
+    }
+
+    #[inline(always)]
+    fn iter(&self) -> BitVecViewIter<'_> {
+        self.view().into_iter()
+    }
+}
+
+impl<P: ProtoSource> IntoIterator for ProtoBitVec<P> {
+    type Item = usize;
+
+    type IntoIter = LProtoBitVecIter;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        LProtoBitVecIter{
+            proto: self.source.proto(),
+            iter: unsafe{self.into_raw().into_iter()}
+        }
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> From<CProtoBitVec<BIT_WIDTH, G>> for ProtoBitVec<ConstProto<BIT_WIDTH>> {
+    #[inline(always)]
+    fn from(bitvec: CProtoBitVec<BIT_WIDTH, G>) -> Self {
+        unsafe {Self::from_raw(bitvec.into_raw(), ConstProto)}
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> From<ProtoBitVec<ConstProto<BIT_WIDTH>>> for CProtoBitVec<BIT_WIDTH, G> {
+    #[inline(always)]
+    fn from(bitvec: ProtoBitVec<ConstProto<BIT_WIDTH>>) -> Self {
+        unsafe {Self::from_raw(bitvec.into_raw())}
+    }
+}
+
+impl From<SProtoBitVec> for ProtoBitVec<&'static BitProto> {
+    #[inline(always)]
+    fn from(bitvec: SProtoBitVec) -> Self {
+        let proto_ref = bitvec.proto;
+        unsafe {Self::from_raw(bitvec.into_raw(), proto_ref)}
+    }
+}
+
+impl From<ProtoBitVec<&'static BitProto>> for SProtoBitVec {
+    #[inline(always)]
+    fn from(bitvec: ProtoBitVec<&'static BitProto>) -> Self {
+        let proto_ref = bitvec.source;
+        unsafe {Self::from_raw(bitvec.into_raw(), proto_ref)}
+    }
+}
+
+impl From<LProtoBitVec> for ProtoBitVec<BitProto> {
+    #[inline(always)]
+    fn from(bitvec: LProtoBitVec) -> Self {
+        let proto = bitvec.proto;
+        unsafe {Self::from_raw(bitvec.into_raw(), proto)}
+    }
+}
+
+impl From<ProtoBitVec<BitProto>> for LProtoBitVec {
+    #[inline(always)]
+    fn from(bitvec: ProtoBitVec<BitProto>) -> Self {
+        let proto = bitvec.source;
+        unsafe {Self::from_raw(bitvec.into_raw(), proto)}
+    }
+}
@@ -1,14 +1,151 @@
 use crate::{
     BitUtil, 
-    Ordering
+    Ordering,
+    TypedBitElem,
+    size_of,
+    ptr,
 };
 
+/// Strategy used to choose the new block capacity when a `BitVec` must reallocate to hold more elements
+/// 
+/// The policy is carried inside the [`BitProto`], so every variant that can choose its own [`BitProto`]
+/// ([`RawBitVec`](crate::RawBitVec), [`SProtoBitVec`](crate::SProtoBitVec), [`LProtoBitVec`](crate::LProtoBitVec)
+/// and any [`TypedBitElem`](crate::TypedBitElem) implementation) can also choose its own growth behavior.
+/// [`CProtoBitVec`](crate::CProtoBitVec) picks its policy with a [`ConstGrowth`] type parameter instead, which defaults
+/// to [`GrowthPolicy::OneAndHalf`]
+/// 
+/// Only the non-exact growth methods (`push()`, `insert()`, `append_iter()`, `grow_for_*()`, etc.) use the policy,
+/// the `grow_exact_for_*()` methods always allocate exactly what was asked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowthPolicy {
+    /// Allocate twice the number of blocks needed
+    Double,
+    /// Allocate 1.5 times the number of blocks needed (default)
+    OneAndHalf,
+    /// Allocate the blocks needed plus enough blocks for the given number of extra elements
+    FixedStep(usize),
+    /// Round the allocation up to the next multiple of the given number of bytes (for example `4096`)
+    PageAligned(usize),
+}
+
+/// A [`GrowthPolicy`] chosen at compile time, used as the second type parameter of
+/// [`CProtoBitVec<BIT_WIDTH, G>`](crate::CProtoBitVec) so each `CProtoBitVec` type can pick its own growth behavior
+/// 
+/// Implement it on your own zero-sized type to use any other [`GrowthPolicy`]
+pub trait ConstGrowth {
+    const POLICY: GrowthPolicy;
+}
+
+/// [`ConstGrowth`] for [`GrowthPolicy::Double`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DoubleGrowth;
+
+impl ConstGrowth for DoubleGrowth {
+    const POLICY: GrowthPolicy = GrowthPolicy::Double;
+}
+
+/// [`ConstGrowth`] for [`GrowthPolicy::OneAndHalf`] (the default)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OneAndHalfGrowth;
+
+impl ConstGrowth for OneAndHalfGrowth {
+    const POLICY: GrowthPolicy = GrowthPolicy::OneAndHalf;
+}
+
+/// [`ConstGrowth`] for [`GrowthPolicy::FixedStep(STEP)`](GrowthPolicy::FixedStep)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixedStepGrowth<const STEP: usize>;
+
+impl<const STEP: usize> ConstGrowth for FixedStepGrowth<STEP> {
+    const POLICY: GrowthPolicy = GrowthPolicy::FixedStep(STEP);
+}
+
+/// [`ConstGrowth`] for [`GrowthPolicy::PageAligned(PAGE_SIZE)`](GrowthPolicy::PageAligned), a `PAGE_SIZE` of `0` fails
+/// to compile when the vec's `PROTO` is evaluated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PageAlignedGrowth<const PAGE_SIZE: usize>;
+
+impl<const PAGE_SIZE: usize> ConstGrowth for PageAlignedGrowth<PAGE_SIZE> {
+    const POLICY: GrowthPolicy = GrowthPolicy::PageAligned(PAGE_SIZE);
+}
+
+/// How `repack()` handles values that do not fit in the new, narrower bit width
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepackMode {
+    /// Return an error reporting the first value that does not fit
+    Checked,
+    /// Keep only the lowest bits that fit in the new bit width
+    Truncate,
+    /// Replace the value with the largest value that fits in the new bit width
+    Saturate,
+}
+
+impl RepackMode {
+    #[inline(always)]
+    pub(crate) fn apply(self, new_proto: BitProto, idx: usize, val: usize) -> Result<usize, String> {
+        match self {
+            RepackMode::Checked => match val > new_proto.MASK {
+                true => Err(format!("value at index {} cannot be represented in {} bits: {}", idx, new_proto.BITS, val)),
+                false => Ok(val)
+            },
+            RepackMode::Truncate => Ok(val & new_proto.MASK),
+            RepackMode::Saturate => Ok(val.min(new_proto.MASK)),
+        }
+    }
+}
+
+/// The error returned by a failed `TryFrom` conversion between `BitVec` variants, holding the vector that could not be
+/// converted (like the `Vec` returned by `<[T; N]>::try_from(Vec<T>)`) so its data is not lost
+pub struct TryFromBitVecError<V> {
+    pub(crate) bitvec: V,
+    pub(crate) message: String,
+}
+
+impl<V> TryFromBitVecError<V> {
+    #[inline(always)]
+    pub(crate) fn new(bitvec: V, message: String) -> Self {
+        Self { bitvec, message }
+    }
+
+    /// Why the conversion failed
+    #[inline(always)]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The vector that could not be converted, unchanged
+    #[inline(always)]
+    pub fn into_inner(self) -> V {
+        self.bitvec
+    }
+}
+
+impl<V> core::fmt::Debug for TryFromBitVecError<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TryFromBitVecError").field("message", &self.message).finish_non_exhaustive()
+    }
+}
+
+impl<V> core::fmt::Display for TryFromBitVecError<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl<V> From<TryFromBitVecError<V>> for String {
+    #[inline(always)]
+    fn from(err: TryFromBitVecError<V>) -> Self {
+        err.message
+    }
+}
+
 #[allow(non_snake_case)]
 #[derive(Debug, Clone, Copy)]
 pub struct BitProto {
     pub(crate) MASK: usize,
     pub(crate) BITS: usize,
-    pub(crate) MAX_CAPACITY: usize
+    pub(crate) MAX_CAPACITY: usize,
+    pub(crate) GROWTH: GrowthPolicy,
 }
 
 impl BitProto {
@@ -32,6 +169,21 @@ impl BitProto {
             MASK: mask,
             BITS: bit_width,
             MAX_CAPACITY: Self::MAX_TRUE_BITS / bit_width,
+            GROWTH: GrowthPolicy::OneAndHalf,
+        }
+    }
+
+    /// The same [`BitProto`] with its [`GrowthPolicy`] replaced by `growth`
+    /// 
+    /// Panics if `growth` is `GrowthPolicy::PageAligned(0)` (a compile error when used in a constant)
+    #[inline(always)]
+    pub const fn with_growth(self, growth: GrowthPolicy) -> Self {
+        if let GrowthPolicy::PageAligned(0) = growth {
+            panic!("page size for GrowthPolicy::PageAligned cannot be 0");
+        }
+        Self {
+            GROWTH: growth,
+            ..self
         }
     }
 
@@ -52,6 +204,40 @@ impl BitProto {
         Self::create(current_bits)
     }
 
+    /// The [`BitProto`] with the fewest bits that can hold every value in `0..=max_value`
+    #[inline(always)]
+    pub const fn for_max_value(max_value: usize) -> Self {
+        match max_value {
+            0 => Self::create(1),
+            _ => Self::create((usize::BITS - max_value.leading_zeros()) as usize)
+        }
+    }
+
+    /// The [`BitProto`] with the fewest bits that can hold every value in `min..=max` stored as `val - min`
+    /// (the same layout as [`Ranged<MIN, MAX>`](crate::Ranged))
+    #[inline(always)]
+    pub const fn for_range(min: i64, max: i64) -> Self {
+        if min > max {
+            panic!("for_range() requires min <= max");
+        }
+        if (max as i128 - min as i128) > usize::MAX as i128 {
+            panic!("for_range() span does not fit in usize");
+        }
+        Self::for_max_value(max.wrapping_sub(min) as u64 as usize)
+    }
+
+    /// The [`BitProto`] used by a [`TypedBitElem`], so a matching [`CProtoBitVec`](crate::CProtoBitVec) can be named as
+    /// `CProtoBitVec<{ BitProto::for_type::<T>().bit_width() }>` for any concrete `T`
+    #[inline(always)]
+    pub const fn for_type<T: TypedBitElem>() -> Self {
+        T::PROTO
+    }
+
+    #[inline(always)]
+    pub const fn bit_width(self) -> usize {
+        self.BITS
+    }
+
     #[inline(always)]
     pub const fn idx_proxy(proto: BitProto, bitwise_idx: usize) -> IdxProxy {
         let total_bits = bitwise_idx * proto.BITS;
@@ -91,6 +277,56 @@ impl BitProto {
         BitUtil::calc_total_bits_in_num_usize(block_count) / proto.BITS
     }
 
+    /// The smallest number of elements that always fills a whole number of `usize` blocks, meaning any
+    /// element whose index is a multiple of this period starts exactly on a block boundary
+    #[inline(always)]
+    pub const fn block_aligned_period(proto: BitProto) -> usize {
+        let shared_pow_2 = 1 << proto.BITS.trailing_zeros();
+        let gcd = if shared_pow_2 > BitUtil::USIZE_BITS { BitUtil::USIZE_BITS } else { shared_pow_2 };
+        BitUtil::USIZE_BITS / gcd
+    }
+
+    #[inline(always)]
+    pub(crate) const fn calc_grown_block_count(proto: BitProto, min_block_count: usize) -> usize {
+        let grown_block_count = match proto.GROWTH {
+            GrowthPolicy::Double => min_block_count.saturating_mul(2),
+            GrowthPolicy::OneAndHalf => min_block_count.saturating_add(min_block_count >> 1),
+            GrowthPolicy::FixedStep(step_elements) => {
+                let step_elements = if step_elements > proto.MAX_CAPACITY { proto.MAX_CAPACITY } else { step_elements };
+                min_block_count.saturating_add(Self::calc_block_count_from_bitwise_count(proto, step_elements))
+            },
+            GrowthPolicy::PageAligned(page_bytes) => {
+                let page_blocks = page_bytes.div_ceil(size_of::<usize>());
+                min_block_count.div_ceil(page_blocks).saturating_mul(page_blocks)
+            },
+        };
+        match grown_block_count > Self::MAX_TRUE_CAP {
+            true => if min_block_count > Self::MAX_TRUE_CAP { min_block_count } else { Self::MAX_TRUE_CAP },
+            false => grown_block_count
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn bits_to_signed(proto: BitProto, bits: usize) -> isize {
+        BitUtil::smear_neg_bit_left(bits, 1 << (proto.BITS - 1)) as isize
+    }
+
+    #[inline(always)]
+    pub(crate) fn signed_to_bits(proto: BitProto, val: isize) -> Result<usize, String> {
+        match val >> (proto.BITS - 1) {
+            0 | -1 => Ok((val as usize) & proto.MASK),
+            _ => Err(format!("signed value cannot be represented in {} bits: {}", proto.BITS, val))
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn check_same_width(from_proto: BitProto, to_proto: BitProto) -> Result<(), String> {
+        match from_proto.BITS == to_proto.BITS {
+            true => Ok(()),
+            false => Err(format!("cannot convert a BitVec with bit width {} into a BitVec with bit width {}", from_proto.BITS, to_proto.BITS))
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn check_value(proto: BitProto, val: usize) -> Result<(), String> {
         match val > proto.MASK {
@@ -115,6 +351,50 @@ impl IdxProxy {
     pub fn idx(&self) -> usize {
         self.bitwise_idx
     }
+
+    #[inline(always)]
+    pub(crate) unsafe fn read_val(&self, blocks_ptr: *const usize) -> usize {
+        let mut block_ptr = blocks_ptr.add(self.real_idx);
+        let mut block_bits = ptr::read(block_ptr);
+        let mut val: usize = (block_bits & self.first_mask) >> self.first_offset;
+        if self.second_mask != 0 {
+            block_ptr = block_ptr.add(1);
+            block_bits = ptr::read(block_ptr);
+            val |= (block_bits & self.second_mask) << self.second_offset;
+        }
+        val
+    }
+
+    #[inline(always)]
+    pub(crate) unsafe fn replace_val(&self, blocks_ptr: *mut usize, new_val: usize) -> usize {
+        let mut block_ptr = blocks_ptr.add(self.real_idx);
+        let mut block_bits = ptr::read(block_ptr);
+        let mut val = (block_bits & self.first_mask) >> self.first_offset;
+        block_bits = (block_bits & !self.first_mask) | (new_val << self.first_offset);
+        ptr::write(block_ptr, block_bits);
+        if self.second_mask != 0 {
+            block_ptr = block_ptr.add(1);
+            block_bits = ptr::read(block_ptr);
+            val |= (block_bits & self.second_mask) << self.second_offset;
+            block_bits = (block_bits & !self.second_mask) | (new_val >> self.second_offset);
+            ptr::write(block_ptr, block_bits);
+        }
+        val
+    }
+
+    #[inline(always)]
+    pub(crate) unsafe fn write_val(&self, blocks_ptr: *mut usize, new_val: usize) {
+        let mut block_ptr = blocks_ptr.add(self.real_idx);
+        let mut block_bits = ptr::read(block_ptr);
+        block_bits = (block_bits & !self.first_mask) | (new_val << self.first_offset);
+        ptr::write(block_ptr, block_bits);
+        if self.second_mask != 0 {
+            block_ptr = block_ptr.add(1);
+            block_bits = ptr::read(block_ptr);
+            block_bits = (block_bits & !self.second_mask) | (new_val >> self.second_offset);
+            ptr::write(block_ptr, block_bits);
+        }
+    }
 }
 
 impl Ord for IdxProxy {
@@ -0,0 +1,190 @@
+use crate::{
+    BitUtil,
+    CProtoBitVec,
+    ptr,
+};
+
+/// ## `RankSelect`: "Rank/Select Bit Vector"
+/// A fixed 1-bit [`CProtoBitVec<1>`] with a small index of cumulative one counts, answering `rank` (how many ones come
+/// before an index) in O(1) and `select` (where the `k`-th one or zero is) in O(log n)
+///
+/// One count is kept for every [`RankSelect::SUPERBLOCK_BLOCKS`] `usize` blocks, `rank` adds the popcounts of at most
+/// that many blocks to it, and `select` binary searches the counts before scanning the same
+///
+/// ### Pros
+/// - Index overhead of one `usize` per `SUPERBLOCK_BLOCKS * usize::BITS` bits (12.5% with the default of 8 blocks)
+/// - The bits are an ordinary [`CProtoBitVec<1>`], returned unchanged by [`RankSelect::into_inner()`]
+///
+/// ### Cons
+/// - Read-only once built
+pub struct RankSelect {
+    pub(crate) bits: CProtoBitVec<1>,
+    /// Ones before each superblock, plus the total at the end
+    pub(crate) super_ranks: Vec<usize>,
+}
+
+impl RankSelect {
+    pub const SUPERBLOCK_BLOCKS: usize = 8;
+    const SUPERBLOCK_BITS: usize = Self::SUPERBLOCK_BLOCKS * BitUtil::USIZE_BITS;
+
+    pub fn new(bits: CProtoBitVec<1>) -> Self {
+        let block_count = bits.len().div_ceil(BitUtil::USIZE_BITS);
+        let tail_bits = bits.len() % BitUtil::USIZE_BITS;
+        if tail_bits != 0 {
+            // Bits past `len()` can be left over from earlier writes, clear them so popcounts can use whole blocks
+            unsafe {
+                let last_block = bits.0.ptr.as_ptr().add(block_count - 1);
+                ptr::write(last_block, ptr::read(last_block) & ((1 << tail_bits) - 1));
+            }
+        }
+        let mut rank_select = Self { bits, super_ranks: Vec::new() };
+        let mut super_ranks = vec![0];
+        let mut ones = 0;
+        for (block_idx, block) in rank_select.blocks().iter().enumerate() {
+            ones += block.count_ones() as usize;
+            if (block_idx + 1).is_multiple_of(Self::SUPERBLOCK_BLOCKS) || block_idx + 1 == block_count {
+                super_ranks.push(ones);
+            }
+        }
+        rank_select.super_ranks = super_ranks;
+        rank_select
+    }
+
+    #[inline(always)]
+    fn blocks(&self) -> &[usize] {
+        let block_count = self.bits.len().div_ceil(BitUtil::USIZE_BITS);
+        match block_count {
+            0 => &[],
+            _ => unsafe {core::slice::from_raw_parts(self.bits.0.ptr.as_ptr(), block_count)}
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn count_ones(&self) -> usize {
+        self.super_ranks[self.super_ranks.len() - 1]
+    }
+
+    #[inline(always)]
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Result<bool, String> {
+        Ok(self.bits.get(idx)? == 1)
+    }
+
+    #[inline(always)]
+    pub fn bits(&self) -> &CProtoBitVec<1> {
+        &self.bits
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> CProtoBitVec<1> {
+        self.bits
+    }
+
+    /// The number of ones in `0..idx`
+    #[inline]
+    pub fn rank1(&self, idx: usize) -> Result<usize, String> {
+        match idx <= self.len() {
+            true => Ok(self.rank1_unchecked(idx)),
+            false => Err(format!("index out of bounds for rank: (idx) {} > {} (len)", idx, self.len()))
+        }
+    }
+
+    /// Same as [`RankSelect::rank1()`], but panics instead of returning `Err` when `idx > self.len()`
+    #[inline]
+    pub(crate) fn rank1_unchecked(&self, idx: usize) -> usize {
+        let superblock = idx / Self::SUPERBLOCK_BITS;
+        let block_idx = idx / BitUtil::USIZE_BITS;
+        let blocks = self.blocks();
+        let mut rank = self.super_ranks[superblock];
+        for block in &blocks[superblock * Self::SUPERBLOCK_BLOCKS..block_idx] {
+            rank += block.count_ones() as usize;
+        }
+        let bit_offset = idx % BitUtil::USIZE_BITS;
+        if bit_offset != 0 {
+            rank += (blocks[block_idx] & ((1 << bit_offset) - 1)).count_ones() as usize;
+        }
+        rank
+    }
+
+    /// The number of zeros in `0..idx`
+    #[inline]
+    pub fn rank0(&self, idx: usize) -> Result<usize, String> {
+        Ok(idx - self.rank1(idx)?)
+    }
+
+    /// The index of the `k`-th one (counting from `0`), or `None` if there are `k` or fewer ones
+    #[inline]
+    pub fn select1(&self, k: usize) -> Option<usize> {
+        self.select::<true>(k)
+    }
+
+    /// The index of the `k`-th zero (counting from `0`), or `None` if there are `k` or fewer zeros
+    #[inline]
+    pub fn select0(&self, k: usize) -> Option<usize> {
+        self.select::<false>(k)
+    }
+
+    #[inline(always)]
+    fn select<const ONES: bool>(&self, k: usize) -> Option<usize> {
+        let count_before = |superblock: usize| match ONES {
+            true => self.super_ranks[superblock],
+            false => (superblock * Self::SUPERBLOCK_BITS).min(self.len()) - self.super_ranks[superblock],
+        };
+        let total = match ONES {
+            true => self.count_ones(),
+            false => self.count_zeros(),
+        };
+        if k >= total {
+            return None;
+        }
+        // Binary search for the last superblock with at most `k` matching bits before it
+        let mut low = 0;
+        let mut high = self.super_ranks.len() - 1;
+        while low + 1 < high {
+            let mid = (low + high) / 2;
+            match count_before(mid) <= k {
+                true => low = mid,
+                false => high = mid,
+            }
+        }
+        let superblock = low;
+        let mut remaining = k - count_before(superblock);
+        let blocks = self.blocks();
+        let mut block_idx = superblock * Self::SUPERBLOCK_BLOCKS;
+        loop {
+            let block = match ONES {
+                true => blocks[block_idx],
+                false => !blocks[block_idx],
+            };
+            let count = block.count_ones() as usize;
+            if remaining < count {
+                return Some(block_idx * BitUtil::USIZE_BITS + Self::select_in_block(block, remaining));
+            }
+            remaining -= count;
+            block_idx += 1;
+        }
+    }
+
+    /// Position of the `k`-th set bit of `block`, which must have more than `k` set bits
+    #[inline(always)]
+    fn select_in_block(mut block: usize, k: usize) -> usize {
+        for _ in 0..k {
+            block &= block - 1;
+        }
+        block.trailing_zeros() as usize
+    }
+}
@@ -10,10 +10,17 @@ use crate::{
     RawBitVecDrain,
     IdxProxy,
     BitProto,
+    BitVecView,
+    RepackMode,
     MemUtil,
+    Ordering,
     Range,
     ManuallyDrop,
     handle_alloc_error,
+    PackedUint,
+    check_unpack_buffer,
+    unpack_blocks,
+    pack_blocks,
 };
 
 /// ## `RawBitVec`: "Raw Bitwise Vector"  
@@ -79,9 +86,23 @@ impl RawBitVec {
         new_vec
     }
 
+    /// Create a `BitVec` holding `count` copies of `val`, written a whole block at a time (see [`RawBitVec::fill()`])
+    /// 
+    /// ## Safety
+    /// The returned [`RawBitVec`] must only be used with `proto`
+    #[inline]
+    pub unsafe fn from_elem(proto: BitProto, val: usize, count: usize) -> Result<Self, String> {
+        BitProto::check_value(proto, val)?;
+        let mut new_vec = Self::new();
+        new_vec.grow_exact_for_total_elements_if_needed(proto, count)?;
+        new_vec.len = count;
+        new_vec.fill_range_unchecked(proto, 0..count, val);
+        Ok(new_vec)
+    }
+
     #[inline]
     pub unsafe fn grow_exact_for_additional_elements_if_needed(&mut self, proto: BitProto, extra_elements: usize) -> Result<(), String> {
-        if usize::MAX - extra_elements > self.len {
+        if extra_elements > usize::MAX - self.len {
             return Err(format!("{} extra elements would overflow usize::MAX", extra_elements));
         }
         self.handle_grow_if_needed(proto, self.len + extra_elements, false)
@@ -94,7 +115,7 @@ impl RawBitVec {
 
     #[inline]
     pub unsafe fn grow_for_additional_elements_if_needed(&mut self, proto: BitProto, extra_elements: usize) -> Result<(), String> {
-        if usize::MAX - extra_elements > self.len {
+        if extra_elements > usize::MAX - self.len {
             return Err(format!("{} extra elements would overflow usize::MAX", extra_elements));
         }
         self.handle_grow_if_needed(proto, self.len + extra_elements, true)
@@ -467,11 +488,298 @@ impl RawBitVec {
         self.write_val_with_idx_proxy(idx_proxy, val);
     }
 
+    /// Push `val` as a two's-complement value of `proto.BITS` bits
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`] (also applies to
+    /// [`RawBitVec::get_signed()`] and [`RawBitVec::set_signed()`])
+    #[inline]
+    pub unsafe fn push_signed(&mut self, proto: BitProto, val: isize) -> Result<(), String> {
+        self.push(proto, BitProto::signed_to_bits(proto, val)?)
+    }
+
+    /// ## Safety
+    /// See [`RawBitVec::push_signed()`]
+    #[inline]
+    pub unsafe fn get_signed(&self, proto: BitProto, idx: usize) -> Result<isize, String> {
+        Ok(BitProto::bits_to_signed(proto, self.get(proto, idx)?))
+    }
+
+    /// ## Safety
+    /// See [`RawBitVec::push_signed()`]
+    #[inline]
+    pub unsafe fn set_signed(&mut self, proto: BitProto, idx: usize, val: isize) -> Result<(), String> {
+        self.set(proto, idx, BitProto::signed_to_bits(proto, val)?)
+    }
+
     #[inline]
     pub fn discard_from_end(&mut self, count: usize) {
         self.len = self.len.saturating_sub(count)
     }
 
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Shorten the `BitVec` to `new_len` elements, does nothing if `new_len >= len`
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            self.len = new_len
+        }
+    }
+
+    /// Same as `grow_for_additional_elements_if_needed()`, named to match `Vec::reserve()`
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`] (also applies to every
+    /// other method from `first()` to `rotate_right()`)
+    #[inline]
+    pub unsafe fn reserve(&mut self, proto: BitProto, extra_elements: usize) -> Result<(), String> {
+        self.grow_for_additional_elements_if_needed(proto, extra_elements)
+    }
+
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn first(&self, proto: BitProto) -> Result<usize, String> {
+        match self.len {
+            0 => Err(String::from("no elements in BitVec to get first")),
+            _ => Ok(self.get_unchecked(proto, 0))
+        }
+    }
+
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn last(&self, proto: BitProto) -> Result<usize, String> {
+        match self.len {
+            0 => Err(String::from("no elements in BitVec to get last")),
+            _ => Ok(self.get_unchecked(proto, self.len - 1))
+        }
+    }
+
+    /// Grow or shrink the `BitVec` to `new_len` elements, setting every new element to `val`
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn resize(&mut self, proto: BitProto, new_len: usize, val: usize) -> Result<(), String> {
+        BitProto::check_value(proto, val)?;
+        match new_len > self.len {
+            true => {
+                self.handle_grow_if_needed(proto, new_len, true)?;
+                let old_len = self.len;
+                self.len = new_len;
+                self.fill_range_unchecked(proto, old_len..new_len, val);
+            },
+            false => self.truncate(new_len)
+        }
+        Ok(())
+    }
+
+    /// Set every element to `val`, writing whole blocks at a time wherever the repeating pattern lines up
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn fill(&mut self, proto: BitProto, val: usize) -> Result<(), String> {
+        BitProto::check_value(proto, val)?;
+        self.fill_range_unchecked(proto, 0..self.len, val);
+        Ok(())
+    }
+
+    /// Append a copy of the elements in `src_range` to the end of the `BitVec`
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn extend_from_within(&mut self, proto: BitProto, src_range: Range<usize>) -> Result<(), String> {
+        if src_range.start > src_range.end || src_range.end > self.len {
+            return Err(format!("index out of bounds for extend_from_within: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", src_range.start, src_range.end, src_range.end, self.len));
+        }
+        let count = src_range.len();
+        if proto.MAX_CAPACITY - count < self.len {
+            return Err(format!("BitVec cannot hold {} more elements, {} elements would reach the maximum capacity ({})", count, proto.MAX_CAPACITY - self.len, proto.MAX_CAPACITY));
+        }
+        self.handle_grow_if_needed(proto, self.len + count, true)?;
+        for idx in src_range {
+            let val = self.get_unchecked(proto, idx);
+            self.push_unchecked(proto, val);
+        }
+        Ok(())
+    }
+
+    /// Split the `BitVec` in two at `at`, returning the elements `[at, len)` and keeping `[0, at)`
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn split_off(&mut self, proto: BitProto, at: usize) -> Result<Self, String> {
+        match at.cmp(&self.len) {
+            Ordering::Greater => Err(format!("index out of bounds for split_off: (idx) {} > {} (len)", at, self.len)),
+            Ordering::Equal => Ok(Self::new()),
+            Ordering::Less => Ok(self.trim_range_unchecked(proto, at..))
+        }
+    }
+
+    /// Reverse the order of the elements in place
+    /// 
+    /// When the bit width divides `usize::BITS` the blocks are reversed and the lanes within each block are swapped
+    /// with a few mask-and-shift passes, other widths swap elements one pair at a time
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn reverse(&mut self, proto: BitProto) {
+        if self.len < 2 {
+            return;
+        }
+        match BitUtil::USIZE_BITS.is_multiple_of(proto.BITS) {
+            true => self.reverse_by_lanes(proto),
+            false => {
+                let mut low_idx = 0;
+                let mut high_idx = self.len - 1;
+                while low_idx < high_idx {
+                    self.swap_unchecked(proto, low_idx, high_idx);
+                    low_idx += 1;
+                    high_idx -= 1;
+                }
+            }
+        }
+    }
+
+    /// Rotate the elements in place so that the element at `mid` becomes the first element
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn rotate_left(&mut self, proto: BitProto, mid: usize) -> Result<(), String> {
+        if mid > self.len {
+            return Err(format!("index out of bounds for rotate_left: (mid) {} > {} (len)", mid, self.len));
+        }
+        if mid != 0 && mid != self.len {
+            match mid <= self.len / 2 {
+                true => {
+                    let front = self.remove_range_unchecked(proto, 0..mid);
+                    self.append_bitvec_unchecked(proto, front);
+                },
+                false => {
+                    let back = self.trim_range_unchecked(proto, mid..);
+                    self.insert_bitvec_unchecked(proto, 0, back);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Rotate the elements in place so that the last `count` elements move to the front
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn rotate_right(&mut self, proto: BitProto, count: usize) -> Result<(), String> {
+        match count > self.len {
+            true => Err(format!("index out of bounds for rotate_right: (count) {} > {} (len)", count, self.len)),
+            false => self.rotate_left(proto, self.len - count)
+        }
+    }
+
+    /// Set every element in `range` to `val`, writing whole blocks at a time wherever the repeating pattern lines up
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn fill_range(&mut self, proto: BitProto, range: Range<usize>, val: usize) -> Result<(), String> {
+        if range.start > range.end || range.end > self.len {
+            return Err(format!("index out of bounds for fill_range: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", range.start, range.end, range.end, self.len));
+        }
+        BitProto::check_value(proto, val)?;
+        self.fill_range_unchecked(proto, range, val);
+        Ok(())
+    }
+
+    /// Copy every element in `range` into `out`, which must be exactly `range.len()` long, decoding whole blocks at
+    /// a time with a kernel specialized for the element width
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn unpack_into<T: PackedUint>(&self, proto: BitProto, range: Range<usize>, out: &mut [T]) -> Result<(), String> {
+        if range.start > range.end || range.end > self.len {
+            return Err(format!("index out of bounds for unpack_into: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", range.start, range.end, range.end, self.len));
+        }
+        check_unpack_buffer::<T>(proto, range.len(), out.len())?;
+        unpack_blocks(proto, self.ptr.as_ptr(), range.start, out);
+        Ok(())
+    }
+
+    /// Overwrite the elements starting at `idx` with the values in `src`, encoding whole blocks at a time with a
+    /// kernel specialized for the element width
+    /// 
+    /// Every value is checked before any are written, so on `Err` the `BitVec` is unchanged
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn pack_from<T: PackedUint>(&mut self, proto: BitProto, idx: usize, src: &[T]) -> Result<(), String> {
+        if idx > self.len || src.len() > self.len - idx {
+            return Err(format!("index out of bounds for pack_from: (idx) {} + {} (src len) > {} (len)", idx, src.len(), self.len));
+        }
+        if src.iter().fold(0, |overflow, val| overflow | val.into_bits()) > proto.MASK {
+            for val in src {
+                BitProto::check_value(proto, val.into_bits())?;
+            }
+        }
+        pack_blocks(proto, self.ptr.as_ptr(), idx, src);
+        Ok(())
+    }
+
+    /// Create a new `BitVec` holding the elements of this one repeated `count` times
+    /// 
+    /// Once enough copies have been written to end exactly on a block boundary, the rest are copied a whole run
+    /// of blocks at a time
+    /// 
+    /// ## Safety
+    /// See [`RawBitVec::reserve()`]
+    #[inline]
+    pub unsafe fn repeat(&self, proto: BitProto, count: usize) -> Result<Self, String> {
+        let total_len = match self.len.checked_mul(count) {
+            Some(total_len) => total_len,
+            None => return Err(format!("repeating {} elements {} times would overflow usize::MAX", self.len, count))
+        };
+        let mut new_vec = Self::new();
+        new_vec.grow_exact_for_total_elements_if_needed(proto, total_len)?;
+        if total_len == 0 {
+            return Ok(new_vec);
+        }
+        // A run of copies ends on a block boundary once its length is a multiple of both `len` and the block period
+        let period = BitProto::block_aligned_period(proto);
+        let period_shift = period.trailing_zeros().saturating_sub(self.len.trailing_zeros());
+        let aligned_run = match self.len.leading_zeros() >= period_shift {
+            true => self.len << period_shift,
+            false => total_len
+        };
+        let first_run = aligned_run.min(total_len);
+        for idx in 0..first_run {
+            let val = self.get_unchecked(proto, idx % self.len);
+            new_vec.push_unchecked(proto, val);
+        }
+        let run_blocks = BitProto::calc_block_count_from_bitwise_count(proto, aligned_run);
+        let whole_runs = (total_len - first_run) / aligned_run;
+        let blocks_ptr = new_vec.ptr.as_ptr();
+        for run in 1..=whole_runs {
+            ptr::copy_nonoverlapping(blocks_ptr, blocks_ptr.add(run * run_blocks), run_blocks);
+        }
+        new_vec.len += whole_runs * aligned_run;
+        while new_vec.len < total_len {
+            let val = self.get_unchecked(proto, new_vec.len % self.len);
+            new_vec.push_unchecked(proto, val);
+        }
+        Ok(new_vec)
+    }
+
     #[inline]
     pub fn drain<'vec>(&'vec mut self) -> RawBitVecDrain<'vec> {
         let len = self.len;
@@ -482,6 +790,28 @@ impl RawBitVec {
         }
     }
 
+    /// Copy every element into a new [`RawBitVec`] that uses `new_proto`, handling values that don't fit according to `mode`
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`], and the returned
+    /// [`RawBitVec`] must only be used with `new_proto`
+    #[inline]
+    pub unsafe fn repack(&self, proto: BitProto, new_proto: BitProto, mode: RepackMode) -> Result<RawBitVec, String> {
+        let mut new_vec = RawBitVec::new();
+        new_vec.grow_exact_for_total_elements_if_needed(new_proto, self.len)?;
+        for (idx, val) in self.view(proto).iter().enumerate() {
+            new_vec.push_unchecked(new_proto, mode.apply(new_proto, idx, val)?);
+        }
+        Ok(new_vec)
+    }
+
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVec`]
+    #[inline]
+    pub unsafe fn view(&self, proto: BitProto) -> BitVecView<'_> {
+        BitVecView::from_raw_parts(proto, self.ptr, self.len)
+    }
+
     #[inline]
     pub fn into_iter(self) -> RawBitVecIter {
         let nodrop_self = ManuallyDrop::new(self);
@@ -495,46 +825,17 @@ impl RawBitVec {
 
     #[inline]
     pub(crate) unsafe fn read_val_with_idx_proxy(&self, idx_proxy: IdxProxy) -> usize {
-        let mut block_ptr = self.ptr.as_ptr().add(idx_proxy.real_idx);
-        let mut block_bits = ptr::read(block_ptr);
-        let mut val: usize = (block_bits & idx_proxy.first_mask) >> idx_proxy.first_offset;
-        if idx_proxy.second_mask != 0 {
-            block_ptr = block_ptr.add(1);
-            block_bits = ptr::read(block_ptr);
-            val = val | ((block_bits & idx_proxy.second_mask) << idx_proxy.second_offset);
-        }
-        val
+        idx_proxy.read_val(self.ptr.as_ptr())
     }
 
     #[inline]
     pub(crate) unsafe fn replace_val_with_idx_proxy(&mut self, idx_proxy: IdxProxy, new_val: usize) -> usize {
-        let mut block_ptr = self.ptr.as_ptr().add(idx_proxy.real_idx);
-        let mut block_bits = ptr::read(block_ptr);
-        let mut val = (block_bits & idx_proxy.first_mask) >> idx_proxy.first_offset;
-        block_bits = (block_bits & !idx_proxy.first_mask) | (new_val << idx_proxy.first_offset);
-        ptr::write(block_ptr, block_bits);
-        if idx_proxy.second_mask != 0 {
-            block_ptr = block_ptr.add(1);
-            block_bits = ptr::read(block_ptr);
-            val = val | ((block_bits & idx_proxy.second_mask) << idx_proxy.second_offset);
-            block_bits = (block_bits & !idx_proxy.second_mask) | (new_val >> idx_proxy.second_offset);
-            ptr::write(block_ptr, block_bits);
-        }
-        val
+        idx_proxy.replace_val(self.ptr.as_ptr(), new_val)
     }
 
     #[inline]
     pub(crate) unsafe fn write_val_with_idx_proxy(&mut self, idx_proxy: IdxProxy, new_val: usize) {
-        let mut block_ptr = self.ptr.as_ptr().add(idx_proxy.real_idx);
-        let mut block_bits = ptr::read(block_ptr);
-        block_bits = (block_bits & !idx_proxy.first_mask) | (new_val << idx_proxy.first_offset);
-        ptr::write(block_ptr, block_bits);
-        if idx_proxy.second_mask != 0 {
-            block_ptr = block_ptr.add(1);
-            block_bits = ptr::read(block_ptr);
-            block_bits = (block_bits & !idx_proxy.second_mask) | (new_val >> idx_proxy.second_offset);
-            ptr::write(block_ptr, block_bits);
-        }
+        idx_proxy.write_val(self.ptr.as_ptr(), new_val)
     }
 
     #[inline]
@@ -627,12 +928,71 @@ impl RawBitVec {
         ptr::write(block_ptr, block_bits | keep_first_bits);
     }
     
+    /// Write `val` to every element in `range`, which must already be within `len`
+    #[inline]
+    pub(crate) unsafe fn fill_range_unchecked(&mut self, proto: BitProto, range: Range<usize>, val: usize) {
+        let period = BitProto::block_aligned_period(proto);
+        let mut idx = range.start;
+        while idx < range.end && !idx.is_multiple_of(period) {
+            self.set_unchecked(proto, idx, val);
+            idx += 1;
+        }
+        let whole_periods = (range.end - idx) / period;
+        if whole_periods > 0 {
+            let period_blocks = BitProto::calc_block_count_from_bitwise_count(proto, period);
+            let mut pattern = [0usize; BitUtil::USIZE_BITS];
+            for pattern_idx in 0..period {
+                BitProto::idx_proxy(proto, pattern_idx).write_val(pattern.as_mut_ptr(), val);
+            }
+            let mut block_ptr = self.ptr.as_ptr().add(BitProto::idx_proxy(proto, idx).real_idx);
+            for _ in 0..whole_periods {
+                ptr::copy_nonoverlapping(pattern.as_ptr(), block_ptr, period_blocks);
+                block_ptr = block_ptr.add(period_blocks);
+            }
+            idx += whole_periods * period;
+        }
+        while idx < range.end {
+            self.set_unchecked(proto, idx, val);
+            idx += 1;
+        }
+    }
+
+    /// Reverse every element when `proto.BITS` divides `usize::BITS` and `len >= 2`
+    #[inline]
+    pub(crate) unsafe fn reverse_by_lanes(&mut self, proto: BitProto) {
+        let lanes = BitUtil::USIZE_BITS / proto.BITS;
+        let block_count = BitProto::calc_block_count_from_bitwise_count(proto, self.len);
+        let blocks_ptr = self.ptr.as_ptr();
+        let mut low_block = 0;
+        let mut high_block = block_count - 1;
+        while low_block < high_block {
+            ptr::swap(blocks_ptr.add(low_block), blocks_ptr.add(high_block));
+            low_block += 1;
+            high_block -= 1;
+        }
+        for block_idx in 0..block_count {
+            let block_ptr = blocks_ptr.add(block_idx);
+            ptr::write(block_ptr, BitUtil::reverse_lanes(ptr::read(block_ptr), proto.BITS));
+        }
+        // The unused lanes of the last block are now at the front, shift the reversed elements down over them
+        let padding = (block_count * lanes) - self.len;
+        if padding > 0 {
+            let real_len = self.len;
+            self.len = block_count * lanes;
+            self.shift_elements_down_with_with_idx_proxy(proto, BitProto::idx_proxy(proto, 0), BitProto::idx_proxy(proto, padding), padding);
+            self.len = real_len;
+        }
+    }
+
     #[inline]
     pub(crate) unsafe fn handle_grow_if_needed(&mut self, proto: BitProto, min_capacity: usize, grow_exponential: bool) -> Result<(), String> {
+        if min_capacity > proto.MAX_CAPACITY {
+            return Err(format!("BitVec cannot hold {} elements, maximum capacity is {}", min_capacity, proto.MAX_CAPACITY));
+        }
         let true_min_capacity = BitProto::calc_block_count_from_bitwise_count(proto, min_capacity);
         if true_min_capacity > self.true_cap{
             let new_true_cap = match grow_exponential {
-                true => true_min_capacity.saturating_add(true_min_capacity >> 1),
+                true => BitProto::calc_grown_block_count(proto, true_min_capacity),
                 false => true_min_capacity,
             };
             let new_layout: Layout = MemUtil::usize_array_layout(new_true_cap);
@@ -670,6 +1030,12 @@ impl RawBitVec {
     }
 }
 
+// SAFETY: a RawBitVec uniquely owns its allocation exactly like a Vec<usize> does, and every method that
+// writes to the blocks requires `&mut self`, so moving it to another thread or sharing `&RawBitVec` between
+// threads (which only allows reads) cannot cause a data race
+unsafe impl Send for RawBitVec {}
+unsafe impl Sync for RawBitVec {}
+
 impl Drop for RawBitVec {
     #[inline]
     fn drop(&mut self) {
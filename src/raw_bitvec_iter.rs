@@ -1,10 +1,11 @@
 use crate::{
-    ptr,
     NonNull,
     alloc,
+    ptr,
     RawBitVec, 
     BitProto, 
     MemUtil,
+    NonZeroUsize,
 };
 
 pub struct RawBitVecIter {
@@ -26,14 +27,7 @@ impl RawBitVecIter {
     #[inline]
     pub unsafe fn next_unchecked(&mut self, proto: BitProto) -> usize {
         let idx_proxy = BitProto::idx_proxy(proto, self.start);
-        let mut block_ptr = self.ptr.as_ptr().add(idx_proxy.real_idx);
-        let mut block_bits = ptr::read(block_ptr);
-        let mut val = (block_bits & idx_proxy.first_mask) >> idx_proxy.first_offset;
-        if idx_proxy.second_mask != 0 {
-            block_ptr = block_ptr.add(1);
-            block_bits = ptr::read(block_ptr);
-            val = val | ((block_bits & idx_proxy.second_mask) << idx_proxy.second_offset);
-        }
+        let val = idx_proxy.read_val(self.ptr.as_ptr());
         self.start += 1;
         val
     }
@@ -49,23 +43,84 @@ impl RawBitVecIter {
     pub unsafe fn next_back_unchecked(&mut self, proto: BitProto) -> usize {
         self.end_excluded -= 1;
         let idx_proxy = BitProto::idx_proxy(proto, self.end_excluded);
-        let mut block_ptr = self.ptr.as_ptr().add(idx_proxy.real_idx);
-        let mut block_bits = ptr::read(block_ptr);
-        let mut val = (block_bits & idx_proxy.first_mask) >> idx_proxy.first_offset;
-        if idx_proxy.second_mask != 0 {
-            block_ptr = block_ptr.add(1);
-            block_bits = ptr::read(block_ptr);
-            val = val | ((block_bits & idx_proxy.second_mask) << idx_proxy.second_offset);
-        }
-        val
+        idx_proxy.read_val(self.ptr.as_ptr())
     }
 
     #[inline(always)]
     pub fn len(&self) -> usize {
         self.end_excluded - self.start
     }
+
+    /// Skip the next `n` elements without reading them, returning the number of elements that could not be skipped
+    /// as an `Err` if fewer than `n` remain (mirrors the unstable `Iterator::advance_by()`)
+    #[inline]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        self.start += step;
+        match NonZeroUsize::new(n - step) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    /// Skip the last `n` elements without reading them, returning the number of elements that could not be skipped
+    /// as an `Err` if fewer than `n` remain (mirrors the unstable `DoubleEndedIterator::advance_back_by()`)
+    #[inline]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        self.end_excluded -= step;
+        match NonZeroUsize::new(n - step) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    /// Skip `n` elements in O(1) and return the one after them
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVecIter`]
+    #[inline]
+    pub unsafe fn nth(&mut self, proto: BitProto, n: usize) -> Option<usize> {
+        match self.advance_by(n) {
+            Ok(()) => self.next(proto),
+            Err(_) => None,
+        }
+    }
+
+    /// Skip the last `n` elements in O(1) and return the one before them
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVecIter`]
+    #[inline]
+    pub unsafe fn nth_back(&mut self, proto: BitProto, n: usize) -> Option<usize> {
+        match self.advance_back_by(n) {
+            Ok(()) => self.next_back(proto),
+            Err(_) => None,
+        }
+    }
+
+    /// Read every remaining element into a `Vec` allocated once with the exact remaining length
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVecIter`]
+    pub unsafe fn collect_vec_with<T, F: FnMut(usize) -> T>(&mut self, proto: BitProto, mut map: F) -> Vec<T> {
+        let len = self.len();
+        let mut out = Vec::with_capacity(len);
+        let out_ptr: *mut T = out.as_mut_ptr();
+        for offset in 0..len {
+            let idx_proxy = BitProto::idx_proxy(proto, self.start + offset);
+            ptr::write(out_ptr.add(offset), map(idx_proxy.read_val(self.ptr.as_ptr())));
+            out.set_len(offset + 1);
+        }
+        self.start = self.end_excluded;
+        out
+    }
 }
 
+// SAFETY: a RawBitVecIter uniquely owns the allocation taken from its RawBitVec, and reading elements requires `&mut self`
+unsafe impl Send for RawBitVecIter {}
+unsafe impl Sync for RawBitVecIter {}
+
 impl Drop for RawBitVecIter  {
     #[inline(always)]
     fn drop(&mut self) {
@@ -118,6 +173,71 @@ impl<'vec> RawBitVecDrain<'vec> {
     pub fn len(&self) -> usize {
         self.end_excluded - self.start
     }
+
+    /// Skip the next `n` elements without reading them, returning the number of elements that could not be skipped
+    /// as an `Err` if fewer than `n` remain (mirrors the unstable `Iterator::advance_by()`)
+    #[inline]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        self.start += step;
+        match NonZeroUsize::new(n - step) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    /// Skip the last `n` elements without reading them, returning the number of elements that could not be skipped
+    /// as an `Err` if fewer than `n` remain (mirrors the unstable `DoubleEndedIterator::advance_back_by()`)
+    #[inline]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        self.end_excluded -= step;
+        match NonZeroUsize::new(n - step) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    /// Skip `n` elements in O(1) and return the one after them
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVecDrain`]
+    #[inline]
+    pub unsafe fn nth(&mut self, proto: BitProto, n: usize) -> Option<usize> {
+        match self.advance_by(n) {
+            Ok(()) => self.next(proto),
+            Err(_) => None,
+        }
+    }
+
+    /// Skip the last `n` elements in O(1) and return the one before them
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVecDrain`]
+    #[inline]
+    pub unsafe fn nth_back(&mut self, proto: BitProto, n: usize) -> Option<usize> {
+        match self.advance_back_by(n) {
+            Ok(()) => self.next_back(proto),
+            Err(_) => None,
+        }
+    }
+
+    /// Read every remaining element into a `Vec` allocated once with the exact remaining length
+    /// 
+    /// ## Safety
+    /// `proto` must be the same [`BitProto`] used for every other call on this [`RawBitVecDrain`]
+    pub unsafe fn collect_vec_with<T, F: FnMut(usize) -> T>(&mut self, proto: BitProto, mut map: F) -> Vec<T> {
+        let len = self.len();
+        let mut out = Vec::with_capacity(len);
+        let out_ptr: *mut T = out.as_mut_ptr();
+        for offset in 0..len {
+            let idx_proxy = BitProto::idx_proxy(proto, self.start + offset);
+            ptr::write(out_ptr.add(offset), map(self.vec.replace_val_with_idx_proxy(idx_proxy, 0)));
+            out.set_len(offset + 1);
+        }
+        self.start = self.end_excluded;
+        out
+    }
 }
 
 impl<'vec> Drop for RawBitVecDrain<'vec>  {
@@ -0,0 +1,332 @@
+use crate::{
+    BitProto,
+    BitVecViewIter,
+    CProtoBitVec,
+    ConstGrowth,
+    LProtoBitVec,
+    RepackMode,
+    TryFromBitVecError,
+    TypedBitElem,
+    TypedBitVec,
+};
+
+/// ## `RleBitVec`: "Run-Length Encoded Bitwise Vector"
+/// A `BitVec` stored as runs of repeated elements, each run a packed value (in the element's bit width) and a packed
+/// run length (in a bit width that grows to fit the longest run)
+///
+/// The start index of every [`RleBitVec::SAMPLE_RUNS`]-th run is kept as a rank sample, so `get()` is a binary search
+/// over the samples followed by a scan of at most `SAMPLE_RUNS` run lengths
+///
+/// ### Pros
+/// - Masks and other small-width columns with long runs take a few bytes per run instead of a few bits per element
+/// - `runs()` hands out whole runs, so many algorithms never need to decode individual elements
+/// - Converts to and from the dense variants with `From`/`TryFrom`
+///
+/// ### Cons
+/// - `get()` is O(log runs) rather than O(1)
+/// - Append-only (no set, insert or remove)
+/// - Larger than the dense variants when runs are short
+pub struct RleBitVec {
+    pub(crate) values: LProtoBitVec,
+    pub(crate) lengths: LProtoBitVec,
+    pub(crate) samples: Vec<usize>,
+    pub(crate) len: usize,
+}
+
+impl RleBitVec {
+    /// The number of runs between rank samples
+    pub const SAMPLE_RUNS: usize = 32;
+    const INITIAL_LENGTH_PROTO: BitProto = BitProto::create(8);
+
+    #[inline(always)]
+    pub fn new(proto: BitProto) -> Self {
+        Self {
+            values: LProtoBitVec::new(proto),
+            lengths: LProtoBitVec::new(Self::INITIAL_LENGTH_PROTO),
+            samples: Vec::new(),
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        self.values.proto()
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub fn run_count(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline(always)]
+    pub fn push(&mut self, val: usize) -> Result<(), String> {
+        self.push_run(val, 1)
+    }
+
+    /// Append `count` copies of `val`, extending the last run if it holds the same value
+    pub fn push_run(&mut self, val: usize, count: usize) -> Result<(), String> {
+        BitProto::check_value(self.proto(), val)?;
+        if count > usize::MAX - self.len {
+            return Err(format!("{} extra elements would overflow usize::MAX", count));
+        }
+        if count == 0 {
+            return Ok(());
+        }
+        match self.values.last() {
+            Ok(last_val) if last_val == val => {
+                let last_run = self.run_count() - 1;
+                let run_len = self.lengths.get(last_run)? + count;
+                self.fit_run_length(run_len)?;
+                self.lengths.set(last_run, run_len)?;
+            },
+            _ => {
+                if self.run_count().is_multiple_of(Self::SAMPLE_RUNS) {
+                    self.samples.push(self.len);
+                }
+                self.fit_run_length(count)?;
+                self.values.push(val)?;
+                self.lengths.push(count)?;
+            }
+        }
+        self.len += count;
+        Ok(())
+    }
+
+    /// Move every run of `bitvec` onto the end of this one, merging the two runs where they meet if they hold the same value
+    pub fn append_bitvec(&mut self, bitvec: Self) -> Result<(), String> {
+        BitProto::check_same_width(self.proto(), bitvec.proto())?;
+        for (val, count) in bitvec.runs() {
+            self.push_run(val, count)?;
+        }
+        Ok(())
+    }
+
+    /// Index of the run holding element `idx`, and the index of that run's first element
+    #[inline]
+    fn find_run(&self, idx: usize) -> (usize, usize) {
+        let sample = self.samples.partition_point(|start| *start <= idx) - 1;
+        let mut run = sample * Self::SAMPLE_RUNS;
+        let mut start = self.samples[sample];
+        loop {
+            let run_len = unsafe {self.lengths.view().get_unchecked(run)};
+            if idx < start + run_len {
+                return (run, start);
+            }
+            start += run_len;
+            run += 1;
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, idx: usize) -> Result<usize, String> {
+        match idx < self.len {
+            true => Ok(unsafe {self.values.view().get_unchecked(self.find_run(idx).0)}),
+            false => Err(format!("index out of bounds for get: (idx) {} >= {} (len)", idx, self.len))
+        }
+    }
+
+    /// Iterate over `(value, run_length)` pairs without decoding individual elements
+    #[inline(always)]
+    pub fn runs(&self) -> RleBitVecRuns<'_> {
+        RleBitVecRuns {
+            values: self.values.iter(),
+            lengths: self.lengths.iter(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> RleBitVecIter<'_> {
+        RleBitVecIter {
+            runs: self.runs(),
+            val: 0,
+            remaining_in_run: 0,
+            remaining: self.len,
+        }
+    }
+
+    /// Decode every run into a dense [`LProtoBitVec`], each run written a block at a time
+    pub fn to_dense(&self) -> Result<LProtoBitVec, String> {
+        let mut dense = LProtoBitVec::with_capacity(self.proto(), self.len);
+        for (val, count) in self.runs() {
+            dense.resize(dense.len() + count, val)?;
+        }
+        Ok(dense)
+    }
+
+    /// Encode the elements of `dense` as runs
+    pub fn from_dense<I: Iterator<Item = usize>>(proto: BitProto, dense: I) -> Result<Self, String> {
+        let mut rle = Self::new(proto);
+        let mut dense = dense.peekable();
+        while let Some(val) = dense.next() {
+            let mut count = 1;
+            while dense.next_if_eq(&val).is_some() {
+                count += 1;
+            }
+            rle.push_run(val, count)?;
+        }
+        Ok(rle)
+    }
+
+    #[inline]
+    fn fit_run_length(&mut self, run_len: usize) -> Result<(), String> {
+        if run_len > self.lengths.proto().MASK {
+            let new_proto = BitProto::create((usize::BITS - run_len.leading_zeros()) as usize);
+            self.lengths = self.lengths.repack(new_proto, RepackMode::Checked)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> From<CProtoBitVec<BIT_WIDTH, G>> for RleBitVec {
+    #[inline]
+    fn from(bitvec: CProtoBitVec<BIT_WIDTH, G>) -> Self {
+        Self::from_dense(CProtoBitVec::<BIT_WIDTH, G>::PROTO, bitvec.iter()).unwrap()
+    }
+}
+
+impl From<LProtoBitVec> for RleBitVec {
+    #[inline]
+    fn from(bitvec: LProtoBitVec) -> Self {
+        Self::from_dense(bitvec.proto(), bitvec.iter()).unwrap()
+    }
+}
+
+impl<T: TypedBitElem> From<TypedBitVec<T>> for RleBitVec {
+    #[inline]
+    fn from(bitvec: TypedBitVec<T>) -> Self {
+        Self::from_dense(T::PROTO, bitvec.view().untyped().iter()).unwrap()
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth> TryFrom<RleBitVec> for CProtoBitVec<BIT_WIDTH, G> {
+    type Error = TryFromBitVecError<RleBitVec>;
+
+    #[inline]
+    fn try_from(bitvec: RleBitVec) -> Result<Self, Self::Error> {
+        let dense = BitProto::check_same_width(bitvec.proto(), Self::PROTO).and_then(|_| bitvec.to_dense());
+        match dense {
+            Ok(dense) => CProtoBitVec::try_from(dense).map_err(|err| TryFromBitVecError::new(bitvec, err.message)),
+            Err(err) => Err(TryFromBitVecError::new(bitvec, err)),
+        }
+    }
+}
+
+impl From<RleBitVec> for LProtoBitVec {
+    #[inline]
+    fn from(bitvec: RleBitVec) -> Self {
+        bitvec.to_dense().unwrap()
+    }
+}
+
+impl<T: TypedBitElem> TryFrom<RleBitVec> for TypedBitVec<T> {
+    type Error = TryFromBitVecError<RleBitVec>;
+
+    #[inline]
+    fn try_from(bitvec: RleBitVec) -> Result<Self, Self::Error> {
+        let dense = BitProto::check_same_width(bitvec.proto(), T::PROTO).and_then(|_| bitvec.to_dense());
+        match dense {
+            Ok(dense) => TypedBitVec::try_from(dense).map_err(|err| TryFromBitVecError::new(bitvec, err.message)),
+            Err(err) => Err(TryFromBitVecError::new(bitvec, err)),
+        }
+    }
+}
+
+pub struct RleBitVecRuns<'vec> {
+    pub(crate) values: BitVecViewIter<'vec>,
+    pub(crate) lengths: BitVecViewIter<'vec>,
+}
+
+impl<'vec> Iterator for RleBitVecRuns<'vec> {
+    type Item = (usize, usize);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.values.next()?, self.lengths.next()?))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        Some((self.values.nth(n)?, self.lengths.nth(n)?))
+    }
+}
+
+impl<'vec> DoubleEndedIterator for RleBitVecRuns<'vec> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((self.values.next_back()?, self.lengths.next_back()?))
+    }
+}
+
+impl<'vec> ExactSizeIterator for RleBitVecRuns<'vec> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+pub struct RleBitVecIter<'vec> {
+    pub(crate) runs: RleBitVecRuns<'vec>,
+    pub(crate) val: usize,
+    pub(crate) remaining_in_run: usize,
+    pub(crate) remaining: usize,
+}
+
+impl<'vec> Iterator for RleBitVecIter<'vec> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_in_run == 0 {
+            (self.val, self.remaining_in_run) = self.runs.next()?;
+        }
+        self.remaining_in_run -= 1;
+        self.remaining -= 1;
+        Some(self.val)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    /// Skips whole runs at a time
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        while n >= self.remaining_in_run {
+            n -= self.remaining_in_run;
+            self.remaining -= self.remaining_in_run;
+            match self.runs.next() {
+                Some((val, run_len)) => (self.val, self.remaining_in_run) = (val, run_len),
+                None => {
+                    self.remaining_in_run = 0;
+                    return None;
+                }
+            }
+        }
+        self.remaining_in_run -= n;
+        self.remaining -= n;
+        self.next()
+    }
+}
+
+impl<'vec> ExactSizeIterator for RleBitVecIter<'vec> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
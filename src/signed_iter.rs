@@ -0,0 +1,57 @@
+use crate::BitProto;
+
+/// An iterator adapter that sign-extends every element from its bit width into an `isize` using two's-complement,
+/// returned by the `iter_signed()` and `into_iter_signed()` methods
+pub struct SignedIter<I: Iterator<Item = usize>> {
+    pub(crate) proto: BitProto,
+    pub(crate) iter: I,
+}
+
+impl<I: Iterator<Item = usize>> Iterator for SignedIter<I> {
+    type Item = isize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|bits| BitProto::bits_to_signed(self.proto, bits))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|bits| BitProto::bits_to_signed(self.proto, bits))
+    }
+
+    #[inline(always)]
+    fn last(self) -> Option<Self::Item> {
+        let proto = self.proto;
+        self.iter.last().map(|bits| BitProto::bits_to_signed(proto, bits))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = usize>> DoubleEndedIterator for SignedIter<I> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|bits| BitProto::bits_to_signed(self.proto, bits))
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n).map(|bits| BitProto::bits_to_signed(self.proto, bits))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = usize>> ExactSizeIterator for SignedIter<I> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
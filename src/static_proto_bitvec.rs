@@ -1,8 +1,18 @@
 use std::ops::RangeFrom;
 
 use crate::{
-    BitProto, 
+    PackedUint,
+    BitProto,
+    BitVecView,
+    BitVecViewChunks,
+    BitVecViewChunksExact,
+    BitVecViewRChunks,
+    BitVecViewWindows,
+    BitVecViewBlockChunks,
+    BitVecViewIter,
     RawBitVec, 
+    RepackMode,
+    SignedIter,
     SProtoBitVecDrain,
     SProtoBitVecIter,
     Range,
@@ -34,6 +44,11 @@ impl SProtoBitVec {
         self.vec.len
     }
 
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        *self.proto
+    }
+
     #[inline(always)]
     pub fn cap(&self) -> usize {
         unsafe {self.vec.cap(*self.proto)}
@@ -54,6 +69,11 @@ impl SProtoBitVec {
         Self { proto: proto_ref, vec: RawBitVec::with_capacity(*proto_ref, cap) }
     }
 
+    #[inline(always)]
+    pub fn from_elem(proto_ref: &'static BitProto, val: usize, count: usize) -> Result<Self, String> {
+        Ok(Self { proto: proto_ref, vec: unsafe {RawBitVec::from_elem(*proto_ref, val, count)?} })
+    }
+
     #[inline(always)]
     pub fn grow_exact_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
         unsafe {self.vec.grow_exact_for_additional_elements_if_needed(*self.proto, extra_elements)}
@@ -256,11 +276,114 @@ impl SProtoBitVec {
         self.vec.set_unchecked(*self.proto, idx, val)
     }
 
+    #[inline(always)]
+    pub fn push_signed(&mut self, val: isize) -> Result<(), String> {
+        unsafe {self.vec.push_signed(*self.proto, val)}
+    }
+
+    #[inline(always)]
+    pub fn get_signed(&self, idx: usize) -> Result<isize, String> {
+        unsafe {self.vec.get_signed(*self.proto, idx)}
+    }
+
+    #[inline(always)]
+    pub fn set_signed(&mut self, idx: usize, val: isize) -> Result<(), String> {
+        unsafe {self.vec.set_signed(*self.proto, idx, val)}
+    }
+
     #[inline]
     pub fn discard_from_end(&mut self, count: usize) {
         self.vec.discard_from_end(count)
     }
 
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.vec.truncate(new_len)
+    }
+
+    #[inline(always)]
+    pub fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+        unsafe {self.vec.reserve(*self.proto, extra_elements)}
+    }
+
+    #[inline(always)]
+    pub fn first(&self) -> Result<usize, String> {
+        unsafe {self.vec.first(*self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn last(&self) -> Result<usize, String> {
+        unsafe {self.vec.last(*self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn resize(&mut self, new_len: usize, val: usize) -> Result<(), String> {
+        unsafe {self.vec.resize(*self.proto, new_len, val)}
+    }
+
+    #[inline(always)]
+    pub fn fill(&mut self, val: usize) -> Result<(), String> {
+        unsafe {self.vec.fill(*self.proto, val)}
+    }
+
+    #[inline(always)]
+    pub fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+        unsafe {self.vec.extend_from_within(*self.proto, src_range)}
+    }
+
+    #[inline(always)]
+    pub fn split_off(&mut self, at: usize) -> Result<Self, String> {
+        let raw = unsafe {self.vec.split_off(*self.proto, at)?};
+        Ok(Self {
+            proto: self.proto,
+            vec: raw
+        })
+    }
+
+    #[inline(always)]
+    pub fn reverse(&mut self) {
+        unsafe {self.vec.reverse(*self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        unsafe {self.vec.rotate_left(*self.proto, mid)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        unsafe {self.vec.rotate_right(*self.proto, count)}
+    }
+
+    #[inline(always)]
+    pub fn fill_range(&mut self, range: Range<usize>, val: usize) -> Result<(), String> {
+        unsafe {self.vec.fill_range(*self.proto, range, val)}
+    }
+
+    #[inline(always)]
+    pub fn unpack_into<U: PackedUint>(&self, range: Range<usize>, out: &mut [U]) -> Result<(), String> {
+        unsafe {self.vec.unpack_into(*self.proto, range, out)}
+    }
+
+    #[inline(always)]
+    pub fn pack_from<U: PackedUint>(&mut self, idx: usize, src: &[U]) -> Result<(), String> {
+        unsafe {self.vec.pack_from(*self.proto, idx, src)}
+    }
+
+    #[inline(always)]
+    pub fn repeat(&self, count: usize) -> Result<Self, String> {
+        let raw = unsafe {self.vec.repeat(*self.proto, count)?};
+        Ok(Self {
+            proto: self.proto,
+            vec: raw
+        })
+    }
+
     #[inline(always)]
     pub fn drain<'vec>(&'vec mut self) -> SProtoBitVecDrain<'vec> {
         SProtoBitVecDrain{
@@ -269,6 +392,59 @@ impl SProtoBitVec {
         }
     }
 
+    #[inline(always)]
+    pub fn view(&self) -> BitVecView<'_> {
+        unsafe {self.vec.view(*self.proto)}
+    }
+
+    #[inline(always)]
+    pub fn chunks(&self, chunk_len: usize) -> Result<BitVecViewChunks<'_>, String> {
+        self.view().chunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn chunks_exact(&self, chunk_len: usize) -> Result<BitVecViewChunksExact<'_>, String> {
+        self.view().chunks_exact(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn rchunks(&self, chunk_len: usize) -> Result<BitVecViewRChunks<'_>, String> {
+        self.view().rchunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn windows(&self, window_len: usize) -> Result<BitVecViewWindows<'_>, String> {
+        self.view().windows(window_len)
+    }
+
+    #[inline(always)]
+    pub fn block_chunks(&self) -> BitVecViewBlockChunks<'_> {
+        self.view().block_chunks()
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> BitVecViewIter<'_> {
+        self.view().into_iter()
+    }
+
+    #[inline(always)]
+    pub fn iter_signed(&self) -> SignedIter<BitVecViewIter<'_>> {
+        SignedIter { proto: self.proto(), iter: self.iter() }
+    }
+
+    #[inline(always)]
+    pub fn into_iter_signed(self) -> SignedIter<SProtoBitVecIter> {
+        SignedIter { proto: self.proto(), iter: self.into_iter() }
+    }
+
+    #[inline(always)]
+    pub fn repack(&self, new_proto_ref: &'static BitProto, mode: RepackMode) -> Result<Self, String> {
+        Ok(Self {
+            proto: new_proto_ref,
+            vec: unsafe {self.vec.repack(*self.proto, *new_proto_ref, mode)?}
+        })
+    }
+
     #[inline(always)]
     pub unsafe fn into_raw(self) -> RawBitVec {
         let nodrop_self = ManuallyDrop::new(self);
@@ -278,6 +454,13 @@ impl SProtoBitVec {
             true_cap: nodrop_self.vec.true_cap 
         }
     }
+
+    /// ## Safety
+    /// `raw` must have only ever been used with a [`BitProto`] of the same bit width as `proto_ref`
+    #[inline(always)]
+    pub unsafe fn from_raw(raw: RawBitVec, proto_ref: &'static BitProto) -> Self {
+        Self { proto: proto_ref, vec: raw }
+    }
 }
 
 impl IntoIterator for SProtoBitVec {
@@ -1,4 +1,5 @@
 use crate::{
+    NonZeroUsize,
     BitProto,
     RawBitVecIter, 
     RawBitVecDrain,
@@ -22,6 +23,21 @@ impl Iterator for SProtoBitVecIter {
         let len = self.iter.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.iter.nth(*self.proto, n)}
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.iter.len()
+    }
 }
 
 impl DoubleEndedIterator for SProtoBitVecIter {
@@ -29,6 +45,11 @@ impl DoubleEndedIterator for SProtoBitVecIter {
     fn next_back(&mut self) -> Option<Self::Item> {
         unsafe {self.iter.next_back(*self.proto)}
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.iter.nth_back(*self.proto, n)}
+    }
 }
 
 impl ExactSizeIterator for SProtoBitVecIter {
@@ -38,6 +59,26 @@ impl ExactSizeIterator for SProtoBitVecIter {
     }
 }
 
+impl SProtoBitVecIter {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecIter::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecIter::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<usize> {
+        unsafe {self.iter.collect_vec_with(*self.proto, |bits| bits)}
+    }
+}
+
 impl Drop for SProtoBitVecIter  {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
@@ -61,6 +102,21 @@ impl<'vec> Iterator for SProtoBitVecDrain<'vec> {
         let len = self.drain.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.drain.nth(*self.proto, n)}
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.drain.len()
+    }
 }
 
 impl<'vec> DoubleEndedIterator for SProtoBitVecDrain<'vec> {
@@ -68,6 +124,11 @@ impl<'vec> DoubleEndedIterator for SProtoBitVecDrain<'vec> {
     fn next_back(&mut self) -> Option<Self::Item> {
         unsafe {self.drain.next_back(*self.proto)}
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.drain.nth_back(*self.proto, n)}
+    }
 }
 
 impl<'vec> ExactSizeIterator for SProtoBitVecDrain<'vec> {
@@ -77,6 +138,26 @@ impl<'vec> ExactSizeIterator for SProtoBitVecDrain<'vec> {
     }
 }
 
+impl<'vec> SProtoBitVecDrain<'vec> {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecDrain::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.drain.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecDrain::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.drain.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<usize> {
+        unsafe {self.drain.collect_vec_with(*self.proto, |bits| bits)}
+    }
+}
+
 impl<'vec> Drop for SProtoBitVecDrain<'vec>  {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
@@ -1,5 +1,39 @@
 mod bit_width_3;
 mod bit_width_4;
+mod atomic_bitvec;
+mod growth_policy;
+#[cfg(feature="small_int_impls")]
+mod thread_safety;
+#[cfg(feature="small_int_impls")]
+mod bitvec_like;
+#[cfg(feature="small_int_impls")]
+mod conversions;
+mod repack;
+#[cfg(feature="small_int_impls")]
+mod signed;
+#[cfg(feature="small_int_impls")]
+mod typed_elems;
+mod ranged;
+mod float_elems;
+mod bit_enum;
+#[cfg(feature="small_int_impls")]
+mod proto_consts;
+mod vec_parity;
+mod fill_repeat;
+mod view_chunks;
+mod iter_fast;
+mod bulk_pack;
+mod for_delta;
+mod rle_bitvec;
+mod dict_bitvec;
+mod elias_fano;
+mod wavelet_matrix;
+#[cfg(feature="small_int_impls")]
+mod checked_elems;
+#[cfg(feature="rayon")]
+mod par_bitvec;
+#[cfg(feature="mmap")]
+mod mmap_bitvec;
 
 #[macro_export]
 macro_rules! assert_bvec_state {
@@ -71,6 +105,14 @@ macro_rules! assert_error {
     };
 }
 
+/// One step of a xorshift64 generator, for reproducible pseudo-random test data
+pub(crate) fn xorshift(state: &mut u64) -> usize {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state as usize
+}
+
 #[macro_export]
 macro_rules! force_write {
     ($VEC:ident, $LEN:expr, $DATA:expr) => {
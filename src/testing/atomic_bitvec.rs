@@ -0,0 +1,91 @@
+use std::sync::atomic::Ordering;
+use crate::*;
+use super::xorshift;
+
+#[test]
+fn load_store_swap_cas() -> Result<(), String> {
+    let bitvec = AtomicBitVec::new(BitProto::create(3), 43);
+    assert_val_result!("1", 0, bitvec.load(21, Ordering::SeqCst));
+    bitvec.store(21, 0b101, Ordering::SeqCst)?;
+    bitvec.store(20, 0b111, Ordering::SeqCst)?;
+    bitvec.store(22, 0b111, Ordering::SeqCst)?;
+    assert_val_result!("2", 0b101, bitvec.load(21, Ordering::SeqCst));
+    assert_val_result!("3", 0b101, bitvec.swap(21, 0b010, Ordering::SeqCst));
+    assert_val_result!("4", 0b010, bitvec.load(21, Ordering::SeqCst));
+    assert_eq!(bitvec.compare_exchange(21, 0b011, 0b110, Ordering::SeqCst, Ordering::SeqCst)?, Err(0b010), "\n5 FAIL: compare_exchange should fail");
+    assert_eq!(bitvec.compare_exchange(21, 0b010, 0b110, Ordering::SeqCst, Ordering::SeqCst)?, Ok(0b010), "\n6 FAIL: compare_exchange should succeed");
+    assert_val_result!("7", 0b110, bitvec.load(21, Ordering::SeqCst));
+    assert_val_result!("8", 0b111, bitvec.load(20, Ordering::SeqCst));
+    assert_val_result!("9", 0b111, bitvec.load(22, Ordering::SeqCst));
+    assert_eq!(bitvec.fetch_update(21, Ordering::SeqCst, Ordering::SeqCst, |val| Some(val + 1))?, Ok(0b110), "\n10 FAIL: fetch_update");
+    assert_error!("11", bitvec.fetch_update(21, Ordering::SeqCst, Ordering::SeqCst, |val| Some(val + 1)));
+    assert_val_result!("12", 0b111, bitvec.load(21, Ordering::SeqCst));
+    assert_error!("13", bitvec.store(43, 0, Ordering::SeqCst));
+    assert_error!("14", bitvec.store(0, 0b1000, Ordering::SeqCst));
+    let lproto = bitvec.into_lproto();
+    assert_val_result!("15", 0b111, lproto.get(21));
+    assert_val_result!("16", 0, lproto.get(0));
+    Ok(())
+}
+
+#[test]
+fn single_bit_fetch_ops() -> Result<(), String> {
+    let bitvec = AtomicBitVec::new(BitProto::create(1), 100);
+    bitvec.store(70, 1, Ordering::SeqCst)?;
+    assert_val_result!("1", 1, bitvec.swap(70, 0, Ordering::SeqCst));
+    assert_val_result!("2", 0, bitvec.swap(70, 1, Ordering::SeqCst));
+    assert_val_result!("3", 1, bitvec.load(70, Ordering::SeqCst));
+    assert_val_result!("4", 0, bitvec.load(69, Ordering::SeqCst));
+    assert_val_result!("5", 0, bitvec.load(71, Ordering::SeqCst));
+    Ok(())
+}
+
+#[test]
+fn concurrent_counters() -> Result<(), String> {
+    let bitvec = AtomicBitVec::new(BitProto::create(7), 30);
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..25 {
+                    for idx in 0..30 {
+                        bitvec.fetch_update(idx, Ordering::AcqRel, Ordering::Acquire, |val| Some(val + 1)).unwrap().unwrap();
+                    }
+                }
+            });
+        }
+    });
+    for idx in 0..30 {
+        assert_val_result!("1", 100, bitvec.load(idx, Ordering::SeqCst));
+    }
+    Ok(())
+}
+
+#[test]
+fn vec_parity_matches_vec() -> Result<(), String> {
+    let mut state = 0xD1B5_4A32_D192_ED03u64;
+    for bits in [1usize, 3, 5, 8, 13, 64] {
+        let proto = BitProto::create(bits);
+        let mut model: Vec<usize> = (0..150).map(|_| xorshift(&mut state) & proto.MASK).collect();
+        let mut lproto = LProtoBitVec::new(proto);
+        lproto.append_iter(model.iter().copied())?;
+        let mut bitvec = AtomicBitVec::from(lproto);
+        assert_eq!(bitvec.first(Ordering::Relaxed)?, model[0], "\n1 FAIL: first (bits = {})", bits);
+        assert_eq!(bitvec.last(Ordering::Relaxed)?, model[149], "\n2 FAIL: last (bits = {})", bits);
+        bitvec.reverse();
+        model.reverse();
+        bitvec.rotate_left(37)?;
+        model.rotate_left(37);
+        bitvec.rotate_right(101)?;
+        model.rotate_right(101);
+        assert_error!("3", bitvec.rotate_left(151));
+        assert_error!("4", bitvec.rotate_right(151));
+        let bitvec_vals: Vec<usize> = (0..150).map(|idx| bitvec.load(idx, Ordering::Relaxed)).collect::<Result<_, _>>()?;
+        assert_eq!(bitvec_vals, model, "\n5 FAIL: reverse + rotations (bits = {})", bits);
+        bitvec.fill(proto.MASK, Ordering::Relaxed)?;
+        assert_eq!(bitvec.into_lproto().iter().collect::<Vec<usize>>(), vec![proto.MASK; 150], "\n6 FAIL: fill (bits = {})", bits);
+    }
+    let empty = AtomicBitVec::new(BitProto::create(3), 0);
+    assert_error!("7", empty.first(Ordering::Relaxed));
+    assert_error!("8", empty.fill(8, Ordering::Relaxed));
+    Ok(())
+}
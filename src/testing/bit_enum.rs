@@ -0,0 +1,49 @@
+use crate::*;
+
+// Shadowing `Result` must not break the code `bit_enum!` generates
+#[allow(dead_code)]
+type Result<T> = core::result::Result<T, ()>;
+
+bit_enum!{
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum State {
+        Idle,
+        Running,
+        Paused,
+        Done,
+        Failed,
+    }
+}
+
+bit_enum!{
+    #[derive(Debug, PartialEq, Eq)]
+    pub(crate) enum Single { Only }
+}
+
+#[test]
+fn bit_enum_elem() -> core::result::Result<(), String> {
+    assert_eq!(State::VARIANT_COUNT, 5, "\n1 FAIL: incorrect variant count");
+    assert_eq!(State::PROTO.BITS, 3, "\n2 FAIL: incorrect width for 5 variants");
+    assert_eq!(Single::PROTO.BITS, 1, "\n3 FAIL: incorrect width for 1 variant");
+    let mut states: TypedBitVec<State> = TypedBitVec::new();
+    states.append_iter([State::Idle, State::Running, State::Paused, State::Done, State::Failed])?;
+    assert_eq!(states.iter().collect::<Vec<State>>(), vec![State::Idle, State::Running, State::Paused, State::Done, State::Failed], "\n4 FAIL: round trip");
+    assert_val_result!("5", 3, unsafe {states.0.get(State::PROTO, 3)});
+    assert_eq!(State::try_from(4), Ok(State::Failed), "\n6 FAIL: decode valid bits");
+    assert_error!("7", State::try_from(5));
+    assert_error!("8", State::try_from(7));
+    assert_error!("9", Single::try_from(1));
+    states.set(1, State::Done)?;
+    assert_eq!(states.get(1)?, State::Done, "\n10 FAIL: set variant");
+    let mut raw_states = LProtoBitVec::new(State::PROTO);
+    raw_states.append_iter([0, 4, 6])?;
+    assert_error!("11", TypedBitVec::<State>::try_from(raw_states));
+    let mut raw_states = CProtoBitVec::<3>::new();
+    raw_states.append_iter([0, 4, 1])?;
+    let states = TypedBitVec::<State>::try_from(raw_states)?;
+    assert_eq!(states.get(2)?, State::Running, "\n12 FAIL: try_from valid bits");
+    let mut raw_options = LProtoBitVec::new(<Option<State>>::PROTO);
+    raw_options.append_iter([0, 0b1001, 0b1101])?;
+    assert_error!("13", TypedBitVec::<Option<State>>::try_from(raw_options));
+    Ok(())
+}
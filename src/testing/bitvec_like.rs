@@ -0,0 +1,44 @@
+use crate::*;
+
+static PROTO_3: BitProto = BitProto::create(3);
+
+fn exercise_untyped<V: BitVecLike<Elem = usize>>(mut bitvec: V) -> Result<(), String> {
+    assert_eq!(bitvec.proto().BITS, 3, "\n1 FAIL: incorrect proto");
+    assert!(bitvec.is_empty(), "\n2 FAIL: new vector not empty");
+    bitvec.append_iter([1, 2, 3, 4, 5])?;
+    bitvec.push(6)?;
+    bitvec.insert(0, 7)?;
+    assert_error!("3", bitvec.push(8));
+    assert_eq!(bitvec.iter().collect::<Vec<usize>>(), vec![7, 1, 2, 3, 4, 5, 6], "\n4 FAIL: incorrect elements");
+    assert_val_result!("5", 7, bitvec.remove(0));
+    assert_val_result!("6", 6, bitvec.pop());
+    bitvec.swap(0, 4)?;
+    assert_val_result!("7", 5, bitvec.get(0));
+    assert_val_result!("8", 5, bitvec.replace(0, 0));
+    bitvec.set(4, 7)?;
+    assert_val_result!("9", 7, bitvec.swap_pop(4));
+    bitvec.insert_iter(1, [6, 6])?;
+    let tail = bitvec.trim_range(4..)?;
+    assert_eq!(tail.iter().collect::<Vec<usize>>(), vec![3, 4], "\n10 FAIL: incorrect trimmed range");
+    bitvec.append_bitvec(tail)?;
+    assert_eq!(bitvec.iter().rev().collect::<Vec<usize>>(), vec![4, 3, 2, 6, 6, 0], "\n11 FAIL: incorrect elements after append");
+    assert_eq!(bitvec.len(), 6, "\n12 FAIL: incorrect len");
+    bitvec.clear();
+    assert!(bitvec.is_empty(), "\n13 FAIL: cleared vector not empty");
+    Ok(())
+}
+
+#[test]
+fn bitvec_like_all_variants() -> Result<(), String> {
+    exercise_untyped(CProtoBitVec::<3>::new())?;
+    exercise_untyped(SProtoBitVec::new(&PROTO_3))?;
+    exercise_untyped(LProtoBitVec::new(PROTO_3))?;
+    exercise_untyped(ProtoBitVec::new(ConstProto::<3>))?;
+    exercise_untyped(ProtoBitVec::new(PROTO_3))?;
+    exercise_untyped(ProtoBitVec::new(&PROTO_3))?;
+    let mut typed: TypedBitVec<u8_as_u3> = TypedBitVec::new();
+    BitVecLike::append_iter(&mut typed, [1u8, 2, 3])?;
+    BitVecLike::insert_iter(&mut typed, 1, [7u8])?;
+    assert_eq!(BitVecLike::iter(&typed).collect::<Vec<u8>>(), vec![1, 7, 2, 3], "\n14 FAIL: typed BitVecLike");
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::*;
+use super::xorshift;
+
+fn check_width<U: PackedUint + PartialEq + core::fmt::Debug>(bits: usize, state: &mut u64) -> Result<(), String> {
+    let proto = BitProto::create(bits);
+    let mut model: Vec<usize> = (0..300).map(|_| xorshift(state) & proto.MASK).collect();
+    let mut lproto = LProtoBitVec::new(proto);
+    lproto.append_iter(model.iter().copied())?;
+    for (start, end) in [(0usize, 300usize), (0, 64), (1, 200), (37, 37), (63, 299), (100, 165), (255, 300)] {
+        let mut out = vec![U::from_bits(0); end - start];
+        lproto.unpack_into(start..end, &mut out)?;
+        let expected: Vec<U> = model[start..end].iter().map(|val| U::from_bits(*val)).collect();
+        assert_eq!(out, expected, "\n1 FAIL: bits {} unpack_into({}..{})", bits, start, end);
+        let view = lproto.view().slice(start..end)?;
+        let mut out = vec![U::from_bits(0); view.len().saturating_sub(3)];
+        if !out.is_empty() {
+            view.unpack_into(3..view.len(), &mut out)?;
+            assert_eq!(out, expected[3..].to_vec(), "\n2 FAIL: bits {} view unpack_into({}..{})", bits, start + 3, end);
+        }
+        let src: Vec<U> = (start..end).map(|_| U::from_bits(xorshift(state) & proto.MASK)).collect();
+        lproto.pack_from(start, &src)?;
+        for (offset, val) in src.iter().enumerate() {
+            model[start + offset] = val.into_bits();
+        }
+        assert_eq!(lproto.iter().collect::<Vec<usize>>(), model, "\n3 FAIL: bits {} pack_from({}, len {})", bits, start, end - start);
+    }
+    Ok(())
+}
+
+#[test]
+fn unpack_and_pack_match_get_and_set() -> Result<(), String> {
+    let mut state = 0x2545_F491_4F6C_DD1D;
+    for bits in 1..=8 {
+        check_width::<u8>(bits, &mut state)?;
+    }
+    for bits in 1..=16 {
+        check_width::<u16>(bits, &mut state)?;
+    }
+    for bits in [1usize, 5, 17, 24, 31, 32] {
+        check_width::<u32>(bits, &mut state)?;
+    }
+    for bits in [3usize, 33, 48, 63, 64] {
+        check_width::<u64>(bits, &mut state)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn unpack_and_pack_errors() -> Result<(), String> {
+    let mut cproto = CProtoBitVec::<12>::new();
+    cproto.append_iter((0..100usize).map(|val| val * 40))?;
+    let mut out_u16 = [0u16; 10];
+    let mut out_u8 = [0u8; 10];
+    assert_error!("1", cproto.unpack_into(95..105, &mut out_u16));
+    assert_error!("2", cproto.unpack_into(0..9, &mut out_u16));
+    assert_error!("3", cproto.unpack_into(0..10, &mut out_u8));
+    cproto.unpack_into(90..100, &mut out_u16)?;
+    assert_eq!(out_u16, [3600, 3640, 3680, 3720, 3760, 3800, 3840, 3880, 3920, 3960], "\n4 FAIL: unpack_into(90..100)");
+    assert_error!("5", cproto.pack_from(95, &[1u16; 6]));
+    assert_error!("6", cproto.pack_from(0, &[1u16, 2, 4096]));
+    assert_eq!(cproto.get(1)?, 40, "\n7 FAIL: pack_from wrote values before failing");
+    cproto.pack_from(98, &[1u16, 2])?;
+    assert_eq!(cproto.get(98)?, 1, "\n8 FAIL: pack_from(98)");
+    assert_eq!(cproto.get(99)?, 2, "\n9 FAIL: pack_from(98)");
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::*;
+
+#[test]
+fn checked_typed_encoding() -> Result<(), String> {
+    let mut typed: TypedBitVec<u8_as_u3> = TypedBitVec::new();
+    assert_error!("1", typed.push(9));
+    assert_eq!(typed.len(), 0, "\n2 FAIL: rejected push changed len");
+    typed.append_iter([1u8, 2, 3])?;
+    assert_error!("3", typed.insert(1, 8));
+    assert_error!("4", typed.set(0, 255));
+    assert_error!("5", typed.replace(0, 200));
+    assert_error!("6", typed.append_iter([4u8, 9, 5]));
+    assert_eq!(typed.iter().collect::<Vec<u8>>(), vec![1, 2, 3], "\n7 FAIL: rejected append_iter left elements behind");
+    assert_error!("8", BitVecLike::insert_iter(&mut typed, 1, [4u8, 5, 16]));
+    assert_eq!(typed.iter().collect::<Vec<u8>>(), vec![1, 2, 3], "\n9 FAIL: rejected insert_iter left elements behind");
+    unsafe {typed.push_unchecked(9)};
+    assert_val_result!("10", 1, typed.get(3));
+    let mut signed: TypedBitVec<i8_as_i4> = TypedBitVec::new();
+    assert_error!("11", signed.push(8));
+    assert_error!("12", signed.push(-9));
+    signed.push(-8)?;
+    let mut chars: TypedBitVec<char> = TypedBitVec::new();
+    assert_error!("13", chars.push('é'));
+    let mut tuples: TypedBitVec<(bool, u8_as_u2)> = TypedBitVec::new();
+    assert_error!("14", tuples.push((true, 4)));
+    let mut options: TypedBitVec<Option<u8_as_u2>> = TypedBitVec::new();
+    assert_error!("15", options.push(Some(4)));
+    options.push(None)?;
+    let mut years: TypedBitVec<Ranged<1900, 2155>> = TypedBitVec::new();
+    assert_error!("16", years.push(2200));
+    Ok(())
+}
+
+#[test]
+fn checked_insert_iter() -> Result<(), String> {
+    let mut typed: TypedBitVec<u8_as_u3> = TypedBitVec::new();
+    typed.append_iter([1u8, 2, 3])?;
+    assert_error!("1", typed.insert_iter(1, [4u8, 9]));
+    assert_eq!(typed.iter().collect::<Vec<u8>>(), vec![1, 2, 3], "\n2 FAIL: rejected insert_iter left elements behind");
+    typed.insert_iter(1, [6u8, 7])?;
+    assert_eq!(typed.iter().collect::<Vec<u8>>(), vec![1, 6, 7, 2, 3], "\n3 FAIL: insert_iter did not insert its values");
+    let mut years: TypedBitVec<Ranged<1900, 2155>> = TypedBitVec::new();
+    years.append_iter([1950i64, 2000])?;
+    assert_error!("4", years.insert_iter(0, [1901i64, 1800]));
+    assert_eq!(years.iter().collect::<Vec<i64>>(), vec![1950, 2000], "\n5 FAIL: rejected Ranged insert_iter left elements behind");
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::*;
+
+static PROTO_3: BitProto = BitProto::create(3);
+
+#[test]
+fn wrapper_conversions() -> Result<(), String> {
+    let mut cproto = CProtoBitVec::<3>::new();
+    cproto.append_iter([1, 2, 3, 4, 5, 6, 7])?;
+    let lproto = LProtoBitVec::from(cproto);
+    assert_eq!(lproto.proto().BITS, 3, "\n1 FAIL: incorrect bit width after From<CProtoBitVec>");
+    assert_eq!(lproto.iter().collect::<Vec<usize>>(), vec![1, 2, 3, 4, 5, 6, 7], "\n2 FAIL: elements lost in From<CProtoBitVec>");
+    let typed: TypedBitVec<u8_as_u3> = TypedBitVec::try_from(lproto)?;
+    assert_eq!(typed.iter().collect::<Vec<u8>>(), vec![1, 2, 3, 4, 5, 6, 7], "\n3 FAIL: elements lost in TryFrom<LProtoBitVec>");
+    let cproto = CProtoBitVec::<3>::try_from(typed)?;
+    assert_val_result!("4", 7, cproto.get(6));
+    assert_error!("5", CProtoBitVec::<4>::try_from(LProtoBitVec::from(cproto)));
+    let mut sproto = SProtoBitVec::new(&PROTO_3);
+    sproto.push(5)?;
+    let generic: ProtoBitVec<&'static BitProto> = sproto.into();
+    let sproto = SProtoBitVec::from(generic);
+    let typed = TypedBitVec::<u8_as_u3>::try_from(sproto)?;
+    assert_val_result!("6", 5, typed.get(0));
+    assert_error!("7", TypedBitVec::<u8_as_u4>::try_from(LProtoBitVec::from(typed)));
+    let mut raw = RawBitVec::new();
+    unsafe {raw.push(PROTO_3, 6)?};
+    let lproto = unsafe {LProtoBitVec::from_raw(raw, PROTO_3)};
+    let generic = ProtoBitVec::<BitProto>::from(lproto);
+    assert_val_result!("8", 6, generic.get(0));
+    let cproto: CProtoBitVec<3> = ProtoBitVec::<ConstProto<3>>::from(CProtoBitVec::<3>::try_from(LProtoBitVec::from(generic))?).into();
+    assert_val_result!("9", 6, cproto.get(0));
+    Ok(())
+}
+
+#[test]
+fn failed_conversions_return_the_bitvec() -> Result<(), String> {
+    let mut lproto = LProtoBitVec::new(PROTO_3);
+    lproto.append_iter([1, 2, 3, 4])?;
+    let err = CProtoBitVec::<4>::try_from(lproto).err().expect("\n1 FAIL: converted a 3-bit vec to a 4-bit vec");
+    assert!(!err.message().is_empty(), "\n2 FAIL: error message is empty");
+    let lproto = err.into_inner();
+    assert_eq!(lproto.iter().collect::<Vec<usize>>(), vec![1, 2, 3, 4], "\n3 FAIL: elements lost in failed TryFrom<LProtoBitVec>");
+    let typed = TypedBitVec::<u8_as_u3>::try_from(lproto)?;
+    let typed = CProtoBitVec::<5>::try_from(typed).err().expect("\n4 FAIL: converted a 3-bit typed vec to a 5-bit vec").into_inner();
+    assert_eq!(typed.iter().collect::<Vec<u8>>(), vec![1, 2, 3, 4], "\n5 FAIL: elements lost in failed TryFrom<TypedBitVec>");
+    let mut sproto = SProtoBitVec::new(&PROTO_3);
+    sproto.append_iter(typed.iter().map(usize::from))?;
+    let sproto = TypedBitVec::<u8_as_u4>::try_from(sproto).err().expect("\n6 FAIL: converted a 3-bit vec to a 4-bit typed vec").into_inner();
+    assert_val_result!("7", 4, sproto.get(3));
+    Ok(())
+}
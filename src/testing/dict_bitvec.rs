@@ -0,0 +1,72 @@
+use std::hash::{BuildHasher, Hasher};
+
+use crate::*;
+
+/// Sends every value to the same hash so lookups have to walk the collision chain
+#[derive(Clone, Copy, Default)]
+struct ConstantHasher;
+
+impl Hasher for ConstantHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+impl BuildHasher for ConstantHasher {
+    type Hasher = ConstantHasher;
+    fn build_hasher(&self) -> Self::Hasher {
+        ConstantHasher
+    }
+}
+
+#[test]
+fn dict_codes_grow_with_dictionary() -> Result<(), String> {
+    let categories = ["red", "green", "blue", "cyan", "magenta"];
+    let model: Vec<String> = (0..1000usize).map(|idx| categories[(idx * 7) % 5].to_string()).collect();
+    let mut column: DictBitVec<String> = model.iter().cloned().collect();
+    assert_eq!(column.len(), 1000, "\n1 FAIL: len");
+    assert_eq!(column.dictionary().len(), 5, "\n2 FAIL: distinct values");
+    assert_eq!(column.bit_width(), 3, "\n3 FAIL: bit_width for 5 distinct values");
+    for (idx, val) in model.iter().enumerate() {
+        assert_eq!(column.get(idx)?, val, "\n4 FAIL: get({})", idx);
+    }
+    assert_eq!(column.iter().collect::<Vec<&String>>(), model.iter().collect::<Vec<&String>>(), "\n5 FAIL: iter");
+    assert_eq!(column.code_of(&String::from("blue")), Some(1), "\n6 FAIL: code_of(blue)");
+    assert_eq!(column.code_of(&String::from("black")), None, "\n7 FAIL: code_of(black)");
+    for idx in 0..20usize {
+        column.push(format!("extra {}", idx))?;
+    }
+    assert_eq!(column.bit_width(), 5, "\n8 FAIL: bit_width after growing to 25 distinct values");
+    assert_eq!(column.get(3)?, &model[3], "\n9 FAIL: get(3) after growing");
+    assert_eq!(column.get(1019)?, "extra 19", "\n10 FAIL: get(1019) after growing");
+    assert_error!("11", column.get(1020));
+    assert_error!("12", column.set(1020, String::from("red")));
+    Ok(())
+}
+
+#[test]
+fn dict_compact_and_collisions() -> Result<(), String> {
+    let mut column = DictBitVec::<u32, ConstantHasher>::with_hasher(ConstantHasher);
+    for val in [10u32, 20, 30, 40, 10, 50, 60, 70, 80, 20] {
+        column.push(val)?;
+    }
+    assert_eq!(column.dictionary(), &[10, 20, 30, 40, 50, 60, 70, 80], "\n1 FAIL: dictionary with colliding hashes");
+    assert_eq!(column.code_of(&80), Some(7), "\n2 FAIL: code_of(80) through the collision chain");
+    assert_eq!(column.bit_width(), 3, "\n3 FAIL: bit_width for 8 distinct values");
+    column.truncate(5);
+    column.set(2, 20)?;
+    assert_eq!(column.pop()?, &10, "\n4 FAIL: pop");
+    assert_eq!(column.iter().copied().collect::<Vec<u32>>(), vec![10, 20, 20, 40], "\n5 FAIL: before compact");
+    column.compact()?;
+    assert_eq!(column.dictionary(), &[10, 20, 40], "\n6 FAIL: dictionary after compact");
+    assert_eq!(column.bit_width(), 2, "\n7 FAIL: bit_width after compact");
+    assert_eq!(column.iter().copied().collect::<Vec<u32>>(), vec![10, 20, 20, 40], "\n8 FAIL: after compact");
+    assert_eq!(column.code_of(&30), None, "\n9 FAIL: code_of(30) after compact");
+    column.push(30)?;
+    assert_eq!(column.code_of(&30), Some(3), "\n10 FAIL: code_of(30) after re-adding");
+    column.truncate(0);
+    column.compact()?;
+    assert!(column.dictionary().is_empty() && column.is_empty(), "\n11 FAIL: compact of an empty column");
+    Ok(())
+}
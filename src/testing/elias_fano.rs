@@ -0,0 +1,72 @@
+use crate::*;
+use super::xorshift;
+
+#[test]
+fn rank_select_matches_model() -> Result<(), String> {
+    let mut state = 0x853C_49E6_748F_EA9B;
+    for (len, density) in [(0usize, 2usize), (1, 2), (63, 2), (64, 3), (511, 2), (512, 5), (3000, 2), (3000, 40), (5000, 1)] {
+        let model: Vec<usize> = (0..len).map(|_| xorshift(&mut state).is_multiple_of(density) as usize).collect();
+        let mut bits = CProtoBitVec::<1>::new();
+        bits.append_iter(model.iter().copied())?;
+        // Stale bits past `len()` must not be counted
+        bits.push(1)?;
+        bits.pop()?;
+        let rank_select = RankSelect::new(bits);
+        assert_eq!(rank_select.count_ones(), model.iter().sum::<usize>(), "\n1 FAIL: len {} count_ones", len);
+        let mut ones = 0;
+        // The extra `0` on the end also checks rank at `len`
+        for (idx, bit) in model.iter().chain([&0]).enumerate() {
+            assert_eq!(rank_select.rank1(idx)?, ones, "\n2 FAIL: len {} rank1({})", len, idx);
+            assert_eq!(rank_select.rank0(idx)?, idx - ones, "\n3 FAIL: len {} rank0({})", len, idx);
+            ones += bit;
+        }
+        assert_error!("4", rank_select.rank1(len + 1));
+        let one_positions: Vec<usize> = (0..len).filter(|idx| model[*idx] == 1).collect();
+        let zero_positions: Vec<usize> = (0..len).filter(|idx| model[*idx] == 0).collect();
+        for (k, pos) in one_positions.iter().enumerate() {
+            assert_eq!(rank_select.select1(k), Some(*pos), "\n5 FAIL: len {} select1({})", len, k);
+        }
+        for (k, pos) in zero_positions.iter().enumerate() {
+            assert_eq!(rank_select.select0(k), Some(*pos), "\n6 FAIL: len {} select0({})", len, k);
+        }
+        assert_eq!(rank_select.select1(one_positions.len()), None, "\n7 FAIL: len {} select1 past the last one", len);
+        assert_eq!(rank_select.select0(zero_positions.len()), None, "\n8 FAIL: len {} select0 past the last zero", len);
+    }
+    Ok(())
+}
+
+#[test]
+fn elias_fano_matches_sorted_model() -> Result<(), String> {
+    let mut state = 0xDA94_2042_E4DD_58B5;
+    for (len, max_gap) in [(0usize, 1usize), (1, 1000), (10, 1), (1000, 3), (1000, 100), (2000, 100_000), (300, 0)] {
+        let mut now = xorshift(&mut state) % 50;
+        let model: Vec<usize> = (0..len).map(|_| {
+            now += xorshift(&mut state) % (max_gap + 1);
+            now
+        }).collect();
+        let ef = EliasFano::from_sorted(model.iter().copied())?;
+        assert_eq!(ef.len(), len, "\n1 FAIL: len {} gap {} len()", len, max_gap);
+        for (idx, val) in model.iter().enumerate() {
+            assert_eq!(ef.get(idx)?, *val, "\n2 FAIL: len {} gap {} get({})", len, max_gap, idx);
+        }
+        assert_error!("3", ef.get(len));
+        assert_eq!(ef.iter().collect::<Vec<usize>>(), model, "\n4 FAIL: len {} gap {} iter", len, max_gap);
+        for n in [0usize, 1, 7, len / 2, len.saturating_sub(1), len] {
+            let mut iter = ef.iter();
+            let mut model_iter = model.iter().copied();
+            assert_eq!(iter.next(), model_iter.next(), "\n5 FAIL: len {} gap {} next before nth({})", len, max_gap, n);
+            assert_eq!(iter.nth(n), model_iter.nth(n), "\n6 FAIL: len {} gap {} nth({})", len, max_gap, n);
+            assert_eq!(iter.next(), model_iter.next(), "\n7 FAIL: len {} gap {} next after nth({})", len, max_gap, n);
+        }
+        let last = model.last().copied().unwrap_or(0);
+        for target in (0..last + 3).step_by((last / 200).max(1)).chain([0, last, last + 1]) {
+            let expected = model.iter().position(|val| *val >= target).map(|idx| (idx, model[idx]));
+            assert_eq!(ef.next_geq(target), expected, "\n8 FAIL: len {} gap {} next_geq({})", len, max_gap, target);
+        }
+    }
+    assert_error!("9", EliasFano::from_sorted([1usize, 5, 4]));
+    let extremes = EliasFano::from_sorted([0usize, usize::MAX / 2, usize::MAX])?;
+    assert_eq!(extremes.iter().collect::<Vec<usize>>(), vec![0, usize::MAX / 2, usize::MAX], "\n10 FAIL: full usize range");
+    assert_eq!(extremes.next_geq(1), Some((1, usize::MAX / 2)), "\n11 FAIL: next_geq over the full usize range");
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::*;
+use super::xorshift;
+
+#[test]
+fn from_elem_repeat_fill_range_match_vec() -> Result<(), String> {
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    for bits in 1..=usize::BITS as usize {
+        let proto = BitProto::create(bits);
+        for count in [0usize, 1, 63, 64, 65, 200, 1000] {
+            let val = xorshift(&mut state) & proto.MASK;
+            let filled = LProtoBitVec::from_elem(proto, val, count)?;
+            assert_eq!(filled.iter().collect::<Vec<usize>>(), vec![val; count], "\n1 FAIL: from_elem (bits = {}, count = {})", bits, count);
+            let src_vals: Vec<usize> = (0..(count % 7) + 1).map(|idx| (val ^ (idx * 0x5A5A_5A5A)) & proto.MASK).collect();
+            let mut src = LProtoBitVec::new(proto);
+            src.append_iter(src_vals.iter().copied())?;
+            let repeated = src.repeat(count / 3)?;
+            assert_eq!(repeated.iter().collect::<Vec<usize>>(), src_vals.repeat(count / 3), "\n2 FAIL: repeat (bits = {}, src len = {}, count = {})", bits, src_vals.len(), count / 3);
+            let mut ranged = repeated;
+            let mut model = src_vals.repeat(count / 3);
+            let range = (model.len() / 5)..(model.len() - model.len() / 7);
+            ranged.fill_range(range.clone(), proto.MASK - val)?;
+            model[range].fill(proto.MASK - val);
+            assert_eq!(ranged.iter().collect::<Vec<usize>>(), model, "\n3 FAIL: fill_range (bits = {}, count = {})", bits, count);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature="small_int_impls")]
+fn from_elem_repeat_fill_range_wrappers() -> Result<(), String> {
+    let cproto = CProtoBitVec::<3>::from_elem(5, 130)?;
+    assert_eq!(cproto.iter().collect::<Vec<usize>>(), vec![5; 130], "\n1 FAIL: CProtoBitVec from_elem");
+    assert_error!("2", CProtoBitVec::<3>::from_elem(8, 10));
+    let typed = TypedBitVec::<i8_as_i5>::from_elem(-7, 40)?;
+    assert_eq!(typed.iter().collect::<Vec<i8>>(), vec![-7; 40], "\n3 FAIL: TypedBitVec from_elem");
+    assert_error!("4", TypedBitVec::<i8_as_i5>::from_elem(-17, 40));
+    let mut sproto = SProtoBitVec::from_elem(&CProtoBitVec::<9>::PROTO, 300, 20)?;
+    sproto.fill_range(5..10, 1)?;
+    assert_eq!(sproto.get(4)?, 300, "\n5 FAIL: fill_range touched before range");
+    assert_eq!(sproto.get(7)?, 1, "\n6 FAIL: fill_range missed range");
+    assert_eq!(sproto.get(10)?, 300, "\n7 FAIL: fill_range touched after range");
+    assert_error!("8", sproto.fill_range(15..21, 1));
+    assert_error!("9", sproto.fill_range(5..10, 512));
+    let generic = ProtoBitVec::from_elem(ConstProto::<4>, 9, 3)?;
+    assert_eq!(generic.repeat(2)?.iter().collect::<Vec<usize>>(), vec![9; 6], "\n10 FAIL: ProtoBitVec repeat");
+    assert_eq!(generic.repeat(0)?.len(), 0, "\n11 FAIL: repeat 0 times not empty");
+    assert_error!("12", generic.repeat(usize::MAX));
+    Ok(())
+}
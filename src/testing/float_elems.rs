@@ -0,0 +1,74 @@
+use crate::*;
+
+#[test]
+fn fixed_elems() -> Result<(), String> {
+    type U4F4 = Fixed<4, 4>;
+    type S4F4 = SFixed<4, 4>;
+    assert_eq!(U4F4::PROTO.BITS, 8, "\n1 FAIL: incorrect width for Fixed<4, 4>");
+    assert_eq!((U4F4::MIN, U4F4::MAX), (0.0, 15.9375), "\n2 FAIL: incorrect Fixed<4, 4> range");
+    assert_eq!((S4F4::MIN, S4F4::MAX), (-8.0, 7.9375), "\n3 FAIL: incorrect SFixed<4, 4> range");
+    let mut unsigned: TypedBitVec<U4F4> = TypedBitVec::new();
+    unsigned.append_iter([0.0f32, 1.5, 3.03, 15.9375])?;
+    assert_eq!(unsigned.iter().collect::<Vec<f32>>(), vec![0.0, 1.5, 3.0, 15.9375], "\n4 FAIL: Fixed round trip");
+    assert_eq!(U4F4::val_to_bits(1.5), 0b_0001_1000, "\n5 FAIL: incorrect bits");
+    assert_eq!(U4F4::val_to_bits(0.03125), 0b_0000_0001, "\n6 FAIL: incorrect bits");
+    assert_eq!(U4F4::val_to_bits(100.0), 255, "\n7 FAIL: incorrect bits");
+    assert_eq!(U4F4::val_to_bits(-1.0), 0, "\n8 FAIL: incorrect bits");
+    assert_eq!(U4F4::val_to_bits(f32::NAN), 0, "\n9 FAIL: incorrect bits");
+    assert_error!("10", U4F4::try_val_to_bits(16.0));
+    assert_error!("11", U4F4::try_val_to_bits(-0.5));
+    assert_error!("12", U4F4::try_val_to_bits(f32::NAN));
+    assert_error!("13", unsigned.push(20.0));
+    let mut signed: TypedBitVec<S4F4> = TypedBitVec::new();
+    signed.append_iter([-8.0f32, -0.0625, 0.0, 7.9375])?;
+    assert_eq!(signed.iter().collect::<Vec<f32>>(), vec![-8.0, -0.0625, 0.0, 7.9375], "\n14 FAIL: SFixed round trip");
+    assert_eq!(S4F4::val_to_bits(-0.0625), 0b_1111_1111, "\n15 FAIL: incorrect bits");
+    assert_eq!(S4F4::val_to_bits(-100.0), 0b_1000_0000, "\n16 FAIL: incorrect bits");
+    assert_eq!(S4F4::val_to_bits(100.0), 0b_0111_1111, "\n17 FAIL: incorrect bits");
+    assert_error!("18", S4F4::try_val_to_bits(-8.5));
+    assert_eq!(<SFixed<1, 0>>::bits_to_val(1), -1.0, "\n19 FAIL: 1 bit SFixed is not -1");
+    assert_eq!(<Fixed<32, 32>>::bits_to_val(<Fixed<32, 32>>::val_to_bits(1234.5)), 1234.5, "\n20 FAIL: 64 bit Fixed round trip");
+    Ok(())
+}
+
+#[test]
+fn minifloat_elems() -> Result<(), String> {
+    assert_eq!((f8_e4m3::PROTO.BITS, f8_e5m2::PROTO.BITS, bf16::PROTO.BITS), (8, 8, 16), "\n1 FAIL: incorrect widths");
+    assert_eq!((f8_e4m3::MAX, f8_e5m2::MAX), (448.0, 57344.0), "\n2 FAIL: incorrect fp8 MAX");
+    assert_eq!(bf16::MAX, f32::from_bits(0x7F7F_0000), "\n3 FAIL: incorrect bf16 MAX");
+    let exact = [0.0f32, -0.0, 1.0, -2.5, 0.015625, 448.0, -448.0, 0.001953125];
+    let mut e4m3: TypedBitVec<f8_e4m3> = TypedBitVec::new();
+    e4m3.append_iter(exact)?;
+    let got = e4m3.iter().collect::<Vec<f32>>();
+    assert_eq!(got, exact.to_vec(), "\n4 FAIL: E4M3 round trip");
+    assert!(got[1].is_sign_negative(), "\n5 FAIL: E4M3 lost the sign of -0.0");
+    assert_eq!(f8_e4m3::val_to_bits(1.0), 0x38, "\n6 FAIL: incorrect bits");
+    assert_eq!(f8_e4m3::val_to_bits(0.001953125), 0x01, "\n7 FAIL: incorrect bits");
+    assert_eq!(f8_e4m3::val_to_bits(1000.0), 0x7E, "\n8 FAIL: incorrect bits");
+    assert_eq!(f8_e4m3::val_to_bits(f32::NEG_INFINITY), 0xFE, "\n9 FAIL: incorrect bits");
+    assert_eq!(f8_e4m3::val_to_bits(f32::NAN), 0x7F, "\n10 FAIL: incorrect bits");
+    assert!(f8_e4m3::bits_to_val(0x7F).is_nan(), "\n11 FAIL: E4M3 0x7F is not NaN");
+    assert_error!("12", f8_e4m3::try_val_to_bits(480.0));
+    assert_error!("13", e4m3.push(f32::INFINITY));
+    // 1.0625 is halfway between 1.0 and 1.125, ties go to the even mantissa 1.0, 1.1875 goes up to 1.25
+    assert_eq!(f8_e4m3::val_to_bits(1.0625), 0x38, "\n14 FAIL: incorrect bits");
+    assert_eq!(f8_e4m3::val_to_bits(1.1875), 0x3A, "\n15 FAIL: incorrect bits");
+    assert_eq!(f8_e4m3::val_to_bits(0.0009765625), 0x00, "\n16 FAIL: incorrect bits");
+    assert_eq!(f8_e4m3::val_to_bits(0.00098), 0x01, "\n17 FAIL: incorrect bits");
+    assert_eq!(f8_e5m2::val_to_bits(1.0), 0x3C, "\n18 FAIL: incorrect bits");
+    assert_eq!(f8_e5m2::val_to_bits(f32::INFINITY), 0x7C, "\n19 FAIL: incorrect bits");
+    assert_eq!(f8_e5m2::val_to_bits(70000.0), 0x7C, "\n20 FAIL: incorrect bits");
+    assert_eq!(f8_e5m2::val_to_bits(57344.0), 0x7B, "\n21 FAIL: incorrect bits");
+    assert_eq!(f8_e5m2::bits_to_val(0xFC), f32::NEG_INFINITY, "\n22 FAIL: E5M2 -inf");
+    assert!(f8_e5m2::bits_to_val(0x7F).is_nan(), "\n23 FAIL: E5M2 0x7F is not NaN");
+    assert_error!("24", f8_e5m2::try_val_to_bits(70000.0));
+    assert_val_result!("25", 0x7C, f8_e5m2::try_val_to_bits(f32::INFINITY));
+    let mut brain: TypedBitVec<bf16> = TypedBitVec::new();
+    brain.append_iter([1.0f32, -3.140625, f32::INFINITY, 1.0e-40])?;
+    assert_eq!(brain.iter().collect::<Vec<f32>>(), vec![1.0, -3.140625, f32::INFINITY, f32::from_bits(0x0001_0000)], "\n26 FAIL: bf16 round trip");
+    assert_eq!(bf16::val_to_bits(f32::from_bits(0x3F80_8000)), 0x3F80, "\n27 FAIL: incorrect bits");
+    assert_eq!(bf16::val_to_bits(f32::from_bits(0x3F81_8000)), 0x3F82, "\n28 FAIL: incorrect bits");
+    assert_eq!(bf16::val_to_bits(f32::from_bits(0x3F80_8001)), 0x3F81, "\n29 FAIL: incorrect bits");
+    assert_eq!(bf16::val_to_bits(f32::MAX), 0x7F80, "\n30 FAIL: incorrect bits");
+    Ok(())
+}
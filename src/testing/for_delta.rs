@@ -0,0 +1,66 @@
+use crate::*;
+use super::xorshift;
+
+#[test]
+fn for_bitvec_picks_minimal_width() -> Result<(), String> {
+    let timestamps: Vec<i64> = (0..500i64).map(|idx| 1_700_000_000 + (idx * 37) % 1000).collect();
+    let mut column: ForBitVec = timestamps.iter().copied().collect();
+    assert_eq!(column.base(), 1_700_000_000, "\n1 FAIL: base");
+    assert_eq!(column.bit_width(), 10, "\n2 FAIL: bit_width for a span of 999");
+    assert_eq!(column.len(), 500, "\n3 FAIL: len");
+    for (idx, val) in timestamps.iter().enumerate() {
+        assert_eq!(column.get(idx)?, *val, "\n4 FAIL: get({})", idx);
+    }
+    assert_eq!(column.iter().collect::<Vec<i64>>(), timestamps, "\n5 FAIL: iter");
+    assert_eq!(column.iter().rev().nth(3), timestamps.iter().rev().nth(3).copied(), "\n6 FAIL: iter().rev().nth(3)");
+    assert_error!("7", column.get(500));
+    column.push(1_700_001_023)?;
+    assert_eq!(column.get(500)?, 1_700_001_023, "\n8 FAIL: push at max_value()");
+    assert_error!("9", column.push(1_700_001_024));
+    assert_error!("10", column.push(1_699_999_999));
+    let extremes: ForBitVec = [i64::MIN, 0, i64::MAX].into_iter().collect();
+    assert_eq!(extremes.bit_width(), 64, "\n11 FAIL: bit_width for the full i64 range");
+    assert_eq!(extremes.iter().collect::<Vec<i64>>(), vec![i64::MIN, 0, i64::MAX], "\n12 FAIL: full i64 range");
+    let constant: ForBitVec = [-5i64; 10].into_iter().collect();
+    assert_eq!(constant.bit_width(), 1, "\n13 FAIL: bit_width for a constant column");
+    assert_eq!(constant.get(9)?, -5, "\n14 FAIL: constant column get(9)");
+    let empty: ForBitVec = core::iter::empty().collect();
+    assert!(empty.is_empty(), "\n15 FAIL: empty column");
+    Ok(())
+}
+
+#[test]
+fn delta_bitvec_matches_source() -> Result<(), String> {
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    let mut now = 1_700_000_000_000i64;
+    let sorted: Vec<i64> = (0..1000).map(|_| {
+        now += (xorshift(&mut state) % 50) as i64;
+        now
+    }).collect();
+    let column: DeltaBitVec = sorted.iter().copied().collect();
+    assert_eq!(column.interval(), DeltaBitVec::DEFAULT_INTERVAL, "\n1 FAIL: default interval");
+    assert_eq!(column.bit_width(), 6, "\n2 FAIL: bit_width for gaps in 0..50");
+    for (idx, val) in sorted.iter().enumerate() {
+        assert_eq!(column.get(idx)?, *val, "\n3 FAIL: get({})", idx);
+    }
+    assert_eq!(column.iter().collect::<Vec<i64>>(), sorted, "\n4 FAIL: iter");
+    for n in [0usize, 1, 63, 64, 65, 200, 999, 1000] {
+        let mut iter = column.iter();
+        let mut model = sorted.iter().copied();
+        assert_eq!(iter.next(), model.next(), "\n5 FAIL: next before nth({})", n);
+        assert_eq!(iter.nth(n), model.nth(n), "\n6 FAIL: nth({})", n);
+        assert_eq!(iter.next(), model.next(), "\n7 FAIL: next after nth({})", n);
+        assert_eq!(iter.len(), model.len(), "\n8 FAIL: len after nth({})", n);
+    }
+    assert_error!("9", column.get(1000));
+    let unsorted: Vec<i64> = (0..300).map(|_| xorshift(&mut state) as i64).collect();
+    for interval in [1usize, 2, 7, 300, 1000] {
+        let column = DeltaBitVec::from_iter_with_interval(unsorted.iter().copied(), interval)?;
+        assert_eq!(column.iter().collect::<Vec<i64>>(), unsorted, "\n10 FAIL: unsorted iter with interval {}", interval);
+        assert_eq!(column.get(299)?, unsorted[299], "\n11 FAIL: unsorted get(299) with interval {}", interval);
+    }
+    assert_error!("12", DeltaBitVec::from_iter_with_interval([1i64, 2], 0));
+    let empty: DeltaBitVec = core::iter::empty().collect();
+    assert_eq!(empty.iter().next(), None, "\n13 FAIL: empty column");
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::*;
+
+#[test]
+fn growth_policies() -> Result<(), String> {
+    let proto = BitProto::create(3);
+    let mut bitvec = LProtoBitVec::new(proto);
+    bitvec.grow_for_total_elements_if_needed(22)?;
+    assert_eq!(bitvec.cap(), 64, "\n1 FAIL: default growth should be 1.5x");
+
+    let mut bitvec = LProtoBitVec::new(proto.with_growth(GrowthPolicy::Double));
+    bitvec.grow_for_total_elements_if_needed(22)?;
+    assert_eq!(bitvec.cap(), 85, "\n2 FAIL: double growth");
+    bitvec.grow_exact_for_total_elements_if_needed(200)?;
+    assert_eq!(bitvec.cap(), 213, "\n3 FAIL: exact growth should ignore policy");
+
+    let mut bitvec = LProtoBitVec::new(proto.with_growth(GrowthPolicy::FixedStep(100)));
+    bitvec.grow_for_total_elements_if_needed(22)?;
+    assert_eq!(bitvec.cap(), 149, "\n4 FAIL: fixed step growth");
+
+    let mut bitvec = LProtoBitVec::new(proto.with_growth(GrowthPolicy::PageAligned(4096)));
+    bitvec.push(0b101)?;
+    assert_eq!(bitvec.cap(), 10922, "\n5 FAIL: page aligned growth");
+    let mut idx = 1;
+    while idx < 10923 {
+        bitvec.push(0b010)?;
+        idx += 1;
+    }
+    assert_eq!(bitvec.cap(), 21845, "\n6 FAIL: page aligned growth");
+
+    let mut cproto = CProtoBitVec::<3>::new();
+    cproto.grow_for_total_elements_if_needed(22)?;
+    assert_eq!(cproto.cap(), 64, "\n7 FAIL: CProtoBitVec default growth should be 1.5x");
+    let mut cproto = CProtoBitVec::<3, DoubleGrowth>::new();
+    cproto.grow_for_total_elements_if_needed(22)?;
+    assert_eq!(cproto.cap(), 85, "\n8 FAIL: CProtoBitVec double growth");
+    assert_eq!(cproto.proto().GROWTH, GrowthPolicy::Double, "\n9 FAIL: CProtoBitVec proto lost its growth policy");
+    let mut cproto = CProtoBitVec::<3, FixedStepGrowth<100>>::new();
+    cproto.grow_for_total_elements_if_needed(22)?;
+    assert_eq!(cproto.cap(), 149, "\n10 FAIL: CProtoBitVec fixed step growth");
+    let mut cproto = CProtoBitVec::<3, PageAlignedGrowth<4096>>::new();
+    cproto.push(0b101)?;
+    assert_eq!(cproto.cap(), 10922, "\n11 FAIL: CProtoBitVec page aligned growth");
+    let lproto = LProtoBitVec::from(cproto);
+    assert_eq!(lproto.proto().GROWTH, GrowthPolicy::PageAligned(4096), "\n12 FAIL: conversion lost the growth policy");
+    let generic = ProtoBitVec::<ConstProto<3>>::from(CProtoBitVec::<3, DoubleGrowth>::from_elem(0b110, 1)?);
+    let mut doubling = CProtoBitVec::<3, DoubleGrowth>::from(generic);
+    assert_val_result!("13", 0b110, doubling.get(0));
+    doubling.grow_for_total_elements_if_needed(22)?;
+    assert_eq!(doubling.cap(), 85, "\n14 FAIL: ConstProto round trip lost the growth policy");
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::*;
+
+fn build(proto: BitProto, vals: &[usize]) -> Result<LProtoBitVec, String> {
+    let mut vec = LProtoBitVec::new(proto);
+    vec.append_iter(vals.iter().copied())?;
+    Ok(vec)
+}
+
+#[test]
+fn iter_nth_and_advance_match_slice() -> Result<(), String> {
+    for bits in [1usize, 3, 7, 13, 32, 63, 64] {
+        let proto = BitProto::create(bits);
+        let model: Vec<usize> = (0..97usize).map(|idx| idx.wrapping_mul(0x9E37_79B9_7F4A_7C15) & proto.MASK).collect();
+        let lproto = build(proto, &model)?;
+        for n in [0usize, 1, 5, 40, 96, 97, 200] {
+            assert_eq!(lproto.iter().nth(n), model.iter().copied().nth(n), "\n1 FAIL: bits {} iter().nth({})", bits, n);
+            assert_eq!(lproto.iter().nth_back(n), model.iter().copied().nth_back(n), "\n2 FAIL: bits {} iter().nth_back({})", bits, n);
+            assert_eq!(build(proto, &model)?.into_iter().nth(n), model.iter().copied().nth(n), "\n3 FAIL: bits {} into_iter().nth({})", bits, n);
+            assert_eq!(build(proto, &model)?.into_iter().nth_back(n), model.iter().copied().nth_back(n), "\n4 FAIL: bits {} into_iter().nth_back({})", bits, n);
+            let mut iter = build(proto, &model)?.into_iter();
+            let mut model_iter = model.iter().copied();
+            loop {
+                let expected = model_iter.nth(n);
+                assert_eq!(iter.nth(n), expected, "\n5 FAIL: bits {} repeated nth({})", bits, n);
+                if expected.is_none() {
+                    break;
+                }
+            }
+            assert_eq!(iter.next(), None, "\n6 FAIL: bits {} nth({}) did not exhaust", bits, n);
+            assert_eq!(lproto.iter().skip(n).collect::<Vec<usize>>(), model.iter().copied().skip(n).collect::<Vec<usize>>(), "\n7 FAIL: bits {} skip({})", bits, n);
+            if n > 0 {
+                assert_eq!(lproto.iter().step_by(n).collect::<Vec<usize>>(), model.iter().copied().step_by(n).collect::<Vec<usize>>(), "\n8 FAIL: bits {} step_by({})", bits, n);
+            }
+        }
+        let mut iter = build(proto, &model)?.into_iter();
+        assert_eq!(iter.advance_by(10), Ok(()), "\n9 FAIL: bits {} advance_by(10)", bits);
+        assert_eq!(iter.advance_back_by(10), Ok(()), "\n10 FAIL: bits {} advance_back_by(10)", bits);
+        assert_eq!(iter.len(), 77, "\n11 FAIL: bits {} len after advancing", bits);
+        assert_eq!(iter.advance_by(80), Err(NonZeroUsize::new(3).unwrap()), "\n12 FAIL: bits {} advance_by past the end", bits);
+        assert_eq!(iter.len(), 0, "\n13 FAIL: bits {} len after advancing past the end", bits);
+        let mut view_iter = lproto.iter();
+        assert_eq!(view_iter.advance_back_by(100), Err(NonZeroUsize::new(3).unwrap()), "\n14 FAIL: bits {} view advance_back_by past the end", bits);
+        assert_eq!(lproto.iter().last(), model.last().copied(), "\n15 FAIL: bits {} last()", bits);
+        assert_eq!(build(proto, &model)?.into_iter().last(), model.last().copied(), "\n16 FAIL: bits {} into_iter().last()", bits);
+        assert_eq!(lproto.iter().count(), 97, "\n17 FAIL: bits {} count()", bits);
+        assert_eq!(build(proto, &model)?.into_iter().skip(5).count(), 92, "\n18 FAIL: bits {} skip(5).count()", bits);
+        let mut iter = build(proto, &model)?.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.collect_vec(), model[1..96].to_vec(), "\n19 FAIL: bits {} collect_vec()", bits);
+        let mut view_iter = lproto.iter();
+        view_iter.nth(20);
+        assert_eq!(view_iter.collect_vec(), model[21..].to_vec(), "\n20 FAIL: bits {} view collect_vec()", bits);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature="small_int_impls")]
+fn drain_and_typed_nth() -> Result<(), String> {
+    let mut cproto = CProtoBitVec::<5>::new();
+    cproto.append_iter((0..40usize).map(|val| val & 31))?;
+    let mut drain = cproto.drain();
+    assert_eq!(drain.nth(10), Some(10), "\n1 FAIL: drain nth(10)");
+    assert_eq!(drain.nth_back(10), Some(29), "\n2 FAIL: drain nth_back(10)");
+    assert_eq!(drain.len(), 18, "\n3 FAIL: drain len");
+    drop(drain);
+    assert_eq!(cproto.len(), 0, "\n4 FAIL: vec not empty after drain");
+    cproto.push(7)?;
+    assert_eq!(cproto.get(0)?, 7, "\n5 FAIL: push after skipped drain");
+    let mut typed = TypedBitVec::<i8_as_i4>::new();
+    typed.append_iter((-8i8..8).chain(-8..8).collect::<Vec<i8>>())?;
+    assert_eq!(typed.iter().nth(3), Some(-5), "\n6 FAIL: typed view nth(3)");
+    assert_eq!(typed.iter().nth_back(0), Some(7), "\n7 FAIL: typed view nth_back(0)");
+    assert_eq!(typed.drain().nth_back(1), Some(6), "\n8 FAIL: typed drain nth_back(1)");
+    typed.append_iter((-8i8..8).chain(-8..8).collect::<Vec<i8>>())?;
+    let mut iter = typed.into_iter();
+    assert_eq!(iter.nth(17), Some(-7), "\n9 FAIL: typed nth(17)");
+    assert_eq!(iter.collect_vec(), (-6i8..8).collect::<Vec<i8>>(), "\n10 FAIL: typed collect_vec()");
+    let mut signed = LProtoBitVec::new(BitProto::create(4));
+    signed.append_iter([0b1000usize, 0b0111, 0b1111])?;
+    assert_eq!(signed.iter_signed().nth(1), Some(7), "\n11 FAIL: signed nth(1)");
+    assert_eq!(signed.iter_signed().last(), Some(-1), "\n12 FAIL: signed last()");
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use crate::*;
+use super::xorshift;
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("grit_bitvec_{}_{}.bin", name, std::process::id()))
+}
+
+#[test]
+fn create_push_reopen() -> Result<(), String> {
+    let path = temp_path("create_push_reopen");
+    let proto = BitProto::create(5);
+    {
+        let mut bitvec = unsafe {MmapBitVec::create(&path, proto)?};
+        assert_eq!(bitvec.len(), 0, "\n1 FAIL: incorrect length");
+        let mut val = 0usize;
+        while val < 100 {
+            bitvec.push(val & 0b11111)?;
+            val += 1;
+        }
+        assert_error!("2", bitvec.push(0b100000));
+        bitvec.set(13, 0b10101)?;
+        assert_val_result!("3", 0b10101, bitvec.get(13));
+        bitvec.flush()?;
+    }
+    {
+        let bitvec = unsafe {MmapBitVec::open_read_only(&path)?};
+        assert_eq!(bitvec.len(), 100, "\n4 FAIL: incorrect length");
+        assert_eq!(bitvec.proto().BITS, 5, "\n5 FAIL: incorrect bit width");
+        assert_val_result!("6", 0b10101, bitvec.get(13));
+        assert_val_result!("7", 12, bitvec.get(12));
+        assert_val_result!("8", 99 & 0b11111, bitvec.get(99));
+        assert_error!("9", bitvec.get(100));
+        let slice = bitvec.slice(30..40)?;
+        assert_eq!(slice.iter().collect::<Vec<_>>(), (30..40).map(|val| val & 0b11111).collect::<Vec<_>>(), "\n10 FAIL: incorrect slice");
+        assert_eq!(bitvec.iter().next_back(), Some(99 & 0b11111), "\n11 FAIL: incorrect reverse iteration");
+        let mut bitvec = bitvec;
+        assert_error!("12", bitvec.set(0, 1));
+    }
+    {
+        let mut bitvec = unsafe {MmapBitVec::open(&path)?};
+        bitvec.append_iter([1usize, 2, 3])?;
+        assert_eq!(bitvec.len(), 103, "\n13 FAIL: incorrect length");
+        assert_val_result!("14", 3, bitvec.get(102));
+    }
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[test]
+fn growth_policy_survives_reopen() -> Result<(), String> {
+    let path = temp_path("growth_policy_survives_reopen");
+    let proto = BitProto::create(3).with_growth(GrowthPolicy::FixedStep(100));
+    {
+        let mut bitvec = unsafe {MmapBitVec::create(&path, proto)?};
+        bitvec.grow_for_total_elements_if_needed(22)?;
+        assert_eq!(bitvec.cap(), 149, "\n1 FAIL: fixed step growth");
+        assert_error!("2", bitvec.append_iter(0..usize::MAX));
+        assert_eq!(bitvec.len(), 0, "\n3 FAIL: failed append changed the length");
+    }
+    {
+        let mut bitvec = unsafe {MmapBitVec::open(&path)?};
+        assert_eq!(bitvec.proto().GROWTH, GrowthPolicy::FixedStep(100), "\n4 FAIL: reopened file lost its growth policy");
+        bitvec.grow_for_total_elements_if_needed(170)?;
+        assert_eq!(bitvec.cap(), 277, "\n5 FAIL: reopened file used the wrong growth policy");
+    }
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[test]
+fn grow_past_max_capacity_is_an_error() -> Result<(), String> {
+    let path = temp_path("grow_past_max_capacity_is_an_error");
+    let proto = BitProto::create(5);
+    {
+        let mut bitvec = unsafe {MmapBitVec::create(&path, proto)?};
+        bitvec.push(7)?;
+        assert_error!("1", bitvec.grow_exact_for_total_elements_if_needed(usize::MAX / 2));
+        assert_error!("2", bitvec.grow_for_total_elements_if_needed(usize::MAX));
+        assert_eq!(bitvec.len(), 1, "\n3 FAIL: failed grow changed the length");
+        assert_val_result!("4", 7, bitvec.get(0));
+    }
+    assert_error!("5", unsafe {MmapBitVec::create_with_capacity(&path, proto, usize::MAX / 2)});
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[test]
+fn vec_parity_matches_vec() -> Result<(), String> {
+    let path = temp_path("vec_parity_matches_vec");
+    for bits in [1usize, 3, 5, 8, 13, 64] {
+        let proto = BitProto::create(bits);
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut model: Vec<usize> = (0..150).map(|_| xorshift(&mut state) & proto.MASK).collect();
+        let mut bitvec = unsafe {MmapBitVec::create(&path, proto)?};
+        bitvec.append_iter(model.iter().copied())?;
+        bitvec.reverse()?;
+        model.reverse();
+        assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n1 FAIL: reverse (bits = {})", bits);
+        assert_eq!(bitvec.first()?, model[0], "\n2 FAIL: first (bits = {})", bits);
+        assert_eq!(bitvec.last()?, model[149], "\n3 FAIL: last (bits = {})", bits);
+        bitvec.rotate_left(37)?;
+        model.rotate_left(37);
+        assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n4 FAIL: rotate_left (bits = {})", bits);
+        bitvec.rotate_right(101)?;
+        model.rotate_right(101);
+        assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n5 FAIL: rotate_right (bits = {})", bits);
+        let tail = bitvec.split_off(100)?;
+        let tail_model = model.split_off(100);
+        assert_eq!(tail.iter().collect::<Vec<usize>>(), tail_model, "\n6 FAIL: split_off returned (bits = {})", bits);
+        assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n7 FAIL: split_off kept (bits = {})", bits);
+        bitvec.extend_from_within(10..60)?;
+        model.extend_from_within(10..60);
+        assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n8 FAIL: extend_from_within (bits = {})", bits);
+        let val = proto.MASK >> 1;
+        bitvec.fill_range(20..40, val)?;
+        model[20..40].fill(val);
+        bitvec.resize(300, 1)?;
+        model.resize(300, 1);
+        assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n9 FAIL: fill_range + resize (bits = {})", bits);
+        bitvec.truncate(90)?;
+        model.truncate(90);
+        bitvec.fill(val)?;
+        model.fill(val);
+        assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n10 FAIL: truncate + fill (bits = {})", bits);
+        assert_error!("11", bitvec.rotate_left(91));
+        assert_error!("12", bitvec.extend_from_within(80..91));
+        assert_error!("13", bitvec.split_off(91));
+        bitvec.flush()?;
+        drop(bitvec);
+        let mut reopened = unsafe {MmapBitVec::open_read_only(&path)?};
+        assert_eq!(reopened.iter().collect::<Vec<usize>>(), model, "\n14 FAIL: reopened (bits = {})", bits);
+        assert_error!("15", reopened.reverse());
+        assert_error!("16", reopened.truncate(0));
+    }
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use rayon::prelude::*;
+use crate::*;
+
+#[test]
+fn par_iter_and_bulk_ops() -> Result<(), String> {
+    let mut bitvec = LProtoBitVec::new(BitProto::create(5));
+    bitvec.append_iter((0..10_000).map(|i| i % 32))?;
+    let par_sum: usize = bitvec.par_iter().sum();
+    let seq_sum: usize = bitvec.iter().sum();
+    assert_eq!(par_sum, seq_sum, "\n1 FAIL: par_iter sum differs from iter sum");
+    let collected: Vec<usize> = bitvec.par_iter().collect();
+    assert_eq!(collected, bitvec.iter().collect::<Vec<usize>>(), "\n2 FAIL: par_iter collect out of order");
+    assert_eq!(bitvec.par_count(|val| val == 31), 10_000 / 32, "\n3 FAIL: par_count");
+    bitvec.par_map(|val| 31 - val)?;
+    assert_val_result!("4", 31, bitvec.get(0));
+    assert_val_result!("5", 0, bitvec.get(9_887));
+    assert_error!("6", bitvec.par_map(|val| val + 1));
+    bitvec.par_fill(0b10101)?;
+    assert!(bitvec.iter().all(|val| val == 0b10101), "\n7 FAIL: par_fill");
+    assert_error!("8", bitvec.par_fill(32));
+    Ok(())
+}
+
+#[test]
+fn par_chunks_mut_block_aligned() -> Result<(), String> {
+    let proto = BitProto::create(3);
+    assert_eq!(BitProto::block_aligned_period(proto), 64, "\n1 FAIL: incorrect period for 3 bits");
+    assert_eq!(BitProto::block_aligned_period(BitProto::create(12)), 16, "\n2 FAIL: incorrect period for 12 bits");
+    let mut bitvec = LProtoBitVec::new(proto);
+    bitvec.append_iter((0..1000).map(|_| 0))?;
+    assert_error!("3", bitvec.par_chunks_mut(0));
+    assert_error!("4", bitvec.par_chunks_mut(100));
+    let lens: Vec<usize> = bitvec.par_chunks_mut(128)?.map(|chunk| chunk.len()).collect();
+    assert_eq!(lens, vec![128, 128, 128, 128, 128, 128, 128, 104], "\n5 FAIL: incorrect chunk lengths");
+    bitvec.par_chunks_mut(128)?.enumerate().try_for_each(|(chunk_idx, mut chunk)| {
+        for idx in 0..chunk.len() {
+            chunk.set(idx, (chunk_idx + idx) % 8)?;
+        }
+        Ok::<(), String>(())
+    })?;
+    for idx in 0..1000 {
+        assert_val_result!("6", ((idx / 128) + (idx % 128)) % 8, bitvec.get(idx));
+    }
+    let mut typed: TypedBitVec<u8_as_u3> = TypedBitVec::new();
+    typed.append_iter((0..200).map(|i| (i % 8) as u8))?;
+    typed.par_map(|val| val ^ 0b111)?;
+    let typed_vals: Vec<u8> = typed.par_iter().collect();
+    assert_eq!(typed_vals, (0..200).map(|i| ((i % 8) as u8) ^ 0b111).collect::<Vec<u8>>(), "\n7 FAIL: typed par_map");
+    assert_error!("9", typed.par_map(|val| val + 8));
+    typed.par_chunks_mut(64)?.for_each(|mut chunk| chunk.set(0, 5).unwrap());
+    assert_eq!(typed.par_count(|val| val == 5), 4 + 25, "\n8 FAIL: typed par_count");
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::*;
+
+const YEAR_PROTO: BitProto = BitProto::for_range(1900, 2155);
+
+#[test]
+fn proto_const_builders() -> Result<(), String> {
+    assert_eq!(BitProto::for_max_value(0).bit_width(), 1, "\n1 FAIL: incorrect width for max 0");
+    assert_eq!(BitProto::for_max_value(1).bit_width(), 1, "\n2 FAIL: incorrect width for max 1");
+    assert_eq!(BitProto::for_max_value(7).bit_width(), 3, "\n3 FAIL: incorrect width for max 7");
+    assert_eq!(BitProto::for_max_value(8).bit_width(), 4, "\n4 FAIL: incorrect width for max 8");
+    assert_eq!(BitProto::for_max_value(usize::MAX).bit_width(), usize::BITS as usize, "\n5 FAIL: incorrect width for usize::MAX");
+    assert_eq!(YEAR_PROTO.bit_width(), Ranged::<1900, 2155>::PROTO.BITS, "\n6 FAIL: for_range disagrees with Ranged");
+    assert_eq!(BitProto::for_range(-3, 3).bit_width(), 3, "\n7 FAIL: incorrect width for -3..=3");
+    assert_eq!(BitProto::for_range(5, 5).bit_width(), 1, "\n8 FAIL: incorrect width for single value range");
+    assert_eq!(BitProto::for_type::<u8_as_u5>().bit_width(), 5, "\n9 FAIL: for_type disagrees with u8_as_u5::PROTO");
+    let bad_range = std::panic::catch_unwind(|| BitProto::for_range(3, -3));
+    assert!(bad_range.is_err(), "\n10 FAIL: for_range accepted min > max");
+    let mut typed: TypedBitVec<u8_as_u5> = TypedBitVec::new();
+    typed.append_iter([1u8, 17, 31])?;
+    let untyped: CProtoBitVec<{ BitProto::for_type::<u8_as_u5>().bit_width() }> = CProtoBitVec::try_from(typed)?;
+    assert_eq!(untyped.iter().collect::<Vec<usize>>(), vec![1, 17, 31], "\n11 FAIL: derived width CProtoBitVec");
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::*;
+
+type Year = Ranged<1900, 2155>;
+
+#[test]
+fn ranged_elem() -> Result<(), String> {
+    assert_eq!(Year::PROTO.BITS, 8, "\n1 FAIL: incorrect width for 1900..=2155");
+    assert_eq!(<Ranged<-3, 3>>::PROTO.BITS, 3, "\n2 FAIL: incorrect width for -3..=3");
+    assert_eq!(<Ranged<5, 5>>::PROTO.BITS, 1, "\n3 FAIL: incorrect width for single value range");
+    assert_eq!(<Ranged<{i64::MIN}, {i64::MAX}>>::PROTO.BITS, 64, "\n4 FAIL: incorrect width for full i64 range");
+    let mut years: TypedBitVec<Year> = TypedBitVec::new();
+    years.append_iter([1900i64, 1969, 2024, 2155])?;
+    assert_eq!(years.iter().collect::<Vec<i64>>(), vec![1900, 1969, 2024, 2155], "\n5 FAIL: round trip");
+    assert_val_result!("6", 124, unsafe {years.0.get(Year::PROTO, 2)});
+    assert_error!("7", Year::try_val_to_bits(1899));
+    assert_error!("8", Year::try_val_to_bits(2156));
+    let mut signed: TypedBitVec<Ranged<-3, 3>> = TypedBitVec::new();
+    signed.append_iter([-3i64, 0, 3])?;
+    assert_eq!(signed.iter().collect::<Vec<i64>>(), vec![-3, 0, 3], "\n9 FAIL: negative range round trip");
+    assert_eq!(Year::val_to_bits(1800), 0, "\n10 FAIL: val_to_bits should clamp a value below MIN");
+    let mut raw_signed = LProtoBitVec::new(<Ranged<-3, 3>>::PROTO);
+    raw_signed.append_iter([0, 6, 7])?;
+    assert_error!("11", TypedBitVec::<Ranged<-3, 3>>::try_from(raw_signed));
+    Ok(())
+}
+
+#[test]
+fn ranged_repack_modes() -> Result<(), String> {
+    let mut wide: TypedBitVec<Ranged<0, 1000>> = TypedBitVec::new();
+    wide.append_iter([500i64, 3, 10, 0])?;
+    let saturated = wide.repack::<Ranged<0, 10>>(RepackMode::Saturate)?;
+    assert_eq!(saturated.iter().collect::<Vec<i64>>(), vec![10, 3, 10, 0], "\n1 FAIL: Saturate should clamp to MAX");
+    let truncated = wide.repack::<Ranged<0, 10>>(RepackMode::Truncate)?;
+    assert_eq!(truncated.iter().collect::<Vec<i64>>(), vec![10, 3, 10, 0], "\n2 FAIL: Truncate should store a valid element");
+    assert_error!("3", wide.repack::<Ranged<0, 10>>(RepackMode::Checked));
+    let mut shifted: TypedBitVec<Ranged<-10, 10>> = TypedBitVec::new();
+    shifted.append_iter([-10i64, -5, 0, 10])?;
+    let saturated = shifted.repack::<Ranged<-3, 3>>(RepackMode::Saturate)?;
+    assert_eq!(saturated.iter().collect::<Vec<i64>>(), vec![-3, -3, 0, 3], "\n4 FAIL: Saturate should clamp to MIN and MAX");
+    let mut unchecked: TypedBitVec<Ranged<-3, 3>> = TypedBitVec::new();
+    unchecked.reserve(1)?;
+    unsafe {unchecked.push_unchecked(7)};
+    assert_val_result!("5", 3, unchecked.get(0));
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+#[test]
+fn repack_widen_and_narrow() -> Result<(), String> {
+    let mut cproto = CProtoBitVec::<3>::new();
+    cproto.append_iter([0, 1, 2, 3, 4, 5, 6, 7])?;
+    let widened = cproto.repack::<5>(RepackMode::Checked)?;
+    assert_eq!(widened.iter().collect::<Vec<usize>>(), vec![0, 1, 2, 3, 4, 5, 6, 7], "\n1 FAIL: widen changed values");
+    let mut lproto = LProtoBitVec::new(BitProto::create(12));
+    lproto.append_iter([5, 1023, 1024, 4095, 17])?;
+    match lproto.repack(BitProto::create(10), RepackMode::Checked) {
+        Err(err) => assert!(err.contains("index 2"), "\n2 FAIL: checked repack reported wrong index: {}", err),
+        Ok(_) => panic!("\n2 FAIL: checked repack should have failed"),
+    }
+    let truncated = lproto.repack(BitProto::create(10), RepackMode::Truncate)?;
+    assert_eq!(truncated.iter().collect::<Vec<usize>>(), vec![5, 1023, 0, 1023, 17], "\n3 FAIL: truncate");
+    let saturated = lproto.repack(BitProto::create(10), RepackMode::Saturate)?;
+    assert_eq!(saturated.iter().collect::<Vec<usize>>(), vec![5, 1023, 1023, 1023, 17], "\n4 FAIL: saturate");
+    assert_eq!(saturated.proto().BITS, 10, "\n5 FAIL: incorrect new bit width");
+    let empty = LProtoBitVec::new(BitProto::create(3)).repack(BitProto::create(7), RepackMode::Checked)?;
+    assert_eq!(empty.len(), 0, "\n6 FAIL: empty repack not empty");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature="small_int_impls")]
+fn typed_map_into() -> Result<(), String> {
+    let mut typed: TypedBitVec<u8_as_u3> = TypedBitVec::new();
+    typed.append_iter([1u8, 3, 5, 7])?;
+    let doubled = typed.map_into::<u8_as_u4>(|val| val * 2)?;
+    assert_eq!(doubled.iter().collect::<Vec<u8>>(), vec![2, 6, 10, 14], "\n1 FAIL: map_into");
+    let signed = typed.map_into::<i8_as_i4>(|val| -(val as i8))?;
+    assert_eq!(signed.iter().collect::<Vec<i8>>(), vec![-1, -3, -5, -7], "\n2 FAIL: map_into signed");
+    let widened = typed.repack::<u8_as_u6>(RepackMode::Checked)?;
+    assert_eq!(widened.iter().collect::<Vec<u8>>(), vec![1, 3, 5, 7], "\n3 FAIL: typed repack");
+    assert_error!("4", typed.map_into::<u8_as_u3>(|val| val * 2));
+    let mut wide: TypedBitVec<u8_as_u6> = TypedBitVec::new();
+    wide.append_iter([1u8, 60])?;
+    assert_error!("5", wide.repack::<u8_as_u3>(RepackMode::Checked));
+    let truncated = wide.repack::<u8_as_u3>(RepackMode::Truncate)?;
+    assert_eq!(truncated.iter().collect::<Vec<u8>>(), vec![1, 4], "\n6 FAIL: typed truncate");
+    let saturated = wide.repack::<u8_as_u3>(RepackMode::Saturate)?;
+    assert_eq!(saturated.iter().collect::<Vec<u8>>(), vec![1, 7], "\n7 FAIL: typed saturate");
+    let mut signed_wide: TypedBitVec<i8_as_i6> = TypedBitVec::new();
+    signed_wide.append_iter([-30i8, 2, 20])?;
+    let signed_saturated = signed_wide.repack::<i8_as_i4>(RepackMode::Saturate)?;
+    assert_eq!(signed_saturated.iter().collect::<Vec<i8>>(), vec![-8, 2, 7], "\n8 FAIL: typed signed saturate");
+    Ok(())
+}
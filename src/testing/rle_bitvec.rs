@@ -0,0 +1,81 @@
+use crate::*;
+use super::xorshift;
+
+#[test]
+fn rle_matches_dense_model() -> Result<(), String> {
+    let mut state = 0xD1B5_4A32_D192_ED03;
+    for bits in [1usize, 2, 5, 64] {
+        let proto = BitProto::create(bits);
+        let mut model = Vec::new();
+        let mut rle = RleBitVec::new(proto);
+        for _ in 0..400 {
+            let val = xorshift(&mut state) & proto.MASK & 0b11;
+            let count = match xorshift(&mut state) % 4 {
+                0 => 1,
+                1 => xorshift(&mut state) % 10,
+                2 => xorshift(&mut state) % 300,
+                _ => xorshift(&mut state) % 5000,
+            };
+            rle.push_run(val, count)?;
+            model.extend(core::iter::repeat_n(val, count));
+        }
+        assert_eq!(rle.len(), model.len(), "\n1 FAIL: bits {} len", bits);
+        for idx in (0..model.len()).step_by(7).chain([0, model.len() - 1]) {
+            assert_eq!(rle.get(idx)?, model[idx], "\n2 FAIL: bits {} get({})", bits, idx);
+        }
+        assert_error!("3", rle.get(model.len()));
+        assert_eq!(rle.iter().collect::<Vec<usize>>(), model, "\n4 FAIL: bits {} iter", bits);
+        let mut runs_total = 0;
+        let mut prev = None;
+        for (val, count) in rle.runs() {
+            assert!(count > 0 && prev != Some(val), "\n5 FAIL: bits {} runs are not maximal", bits);
+            runs_total += count;
+            prev = Some(val);
+        }
+        assert_eq!(runs_total, model.len(), "\n6 FAIL: bits {} runs cover len", bits);
+        for n in [0usize, 1, 299, 5000, model.len() - 1, model.len()] {
+            let mut iter = rle.iter();
+            let mut model_iter = model.iter().copied();
+            assert_eq!(iter.nth(n), model_iter.nth(n), "\n7 FAIL: bits {} nth({})", bits, n);
+            assert_eq!(iter.next(), model_iter.next(), "\n8 FAIL: bits {} next after nth({})", bits, n);
+            assert_eq!(iter.len(), model_iter.len(), "\n9 FAIL: bits {} len after nth({})", bits, n);
+        }
+        let dense = rle.to_dense()?;
+        assert_eq!(dense.iter().collect::<Vec<usize>>(), model, "\n10 FAIL: bits {} to_dense", bits);
+        let rebuilt = RleBitVec::from(dense);
+        assert_eq!(rebuilt.run_count(), rle.run_count(), "\n11 FAIL: bits {} run_count after round trip", bits);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature="small_int_impls")]
+fn rle_conversions_and_append() -> Result<(), String> {
+    let mut mask = CProtoBitVec::<1>::new();
+    mask.resize(1000, 1)?;
+    mask.resize(1500, 0)?;
+    mask.resize(70_000, 1)?;
+    let mut rle = RleBitVec::from(mask);
+    assert_eq!(rle.run_count(), 3, "\n1 FAIL: run_count");
+    assert_eq!(rle.runs().collect::<Vec<(usize, usize)>>(), vec![(1, 1000), (0, 500), (1, 68_500)], "\n2 FAIL: runs");
+    let mut tail = RleBitVec::new(BitProto::create(1));
+    tail.push(1)?;
+    tail.push(0)?;
+    rle.append_bitvec(tail)?;
+    assert_eq!(rle.run_count(), 4, "\n3 FAIL: append_bitvec merges the touching runs");
+    assert_eq!(rle.get(69_999)?, 1, "\n4 FAIL: get(69_999)");
+    assert_eq!(rle.get(70_001)?, 0, "\n5 FAIL: get(70_001)");
+    assert_error!("6", rle.push(2));
+    assert_error!("7", rle.append_bitvec(RleBitVec::new(BitProto::create(2))));
+    let dense = CProtoBitVec::<1>::try_from(rle)?;
+    assert_eq!(dense.len(), 70_002, "\n8 FAIL: dense len");
+    assert_eq!(dense.get(1200)?, 0, "\n9 FAIL: dense get(1200)");
+    assert_error!("10", CProtoBitVec::<2>::try_from(RleBitVec::from(dense)));
+    let mut typed = TypedBitVec::<u8_as_u3>::new();
+    typed.append_iter([7u8, 7, 7, 2, 2, 5])?;
+    let rle = RleBitVec::from(typed);
+    assert_eq!(rle.runs().collect::<Vec<(usize, usize)>>(), vec![(7, 3), (2, 2), (5, 1)], "\n11 FAIL: typed runs");
+    let typed = TypedBitVec::<u8_as_u3>::try_from(rle)?;
+    assert_eq!(typed.iter().collect::<Vec<u8>>(), vec![7, 7, 7, 2, 2, 5], "\n12 FAIL: typed round trip");
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::*;
+
+#[test]
+fn signed_api_sign_extension() -> Result<(), String> {
+    let mut cproto = CProtoBitVec::<5>::new();
+    for val in [-16, -1, 0, 1, 15] {
+        cproto.push_signed(val)?;
+    }
+    assert_error!("1", cproto.push_signed(16));
+    assert_error!("2", cproto.push_signed(-17));
+    assert_val_result!("3", 0b10000, cproto.get(0));
+    assert_val_result!("4", 0b11111, cproto.get(1));
+    assert_eq!(cproto.get_signed(0)?, -16, "\n5 FAIL: get_signed");
+    assert_eq!(cproto.get_signed(4)?, 15, "\n6 FAIL: get_signed");
+    cproto.set_signed(2, -7)?;
+    assert_eq!(cproto.iter_signed().collect::<Vec<isize>>(), vec![-16, -1, -7, 1, 15], "\n7 FAIL: iter_signed");
+    assert_eq!(cproto.into_iter_signed().rev().collect::<Vec<isize>>(), vec![15, 1, -7, -1, -16], "\n8 FAIL: into_iter_signed");
+    let mut lproto = LProtoBitVec::new(BitProto::create(11));
+    lproto.append_iter([0b10000000000, 0b01111111111, 0b11111111111])?;
+    assert_eq!(lproto.iter_signed().collect::<Vec<isize>>(), vec![-1024, 1023, -1], "\n9 FAIL: runtime width iter_signed");
+    let mut full = LProtoBitVec::new(BitProto::create(usize::BITS as usize));
+    full.push_signed(isize::MIN)?;
+    full.push_signed(isize::MAX)?;
+    assert_eq!(full.iter_signed().collect::<Vec<isize>>(), vec![isize::MIN, isize::MAX], "\n10 FAIL: full width signed");
+    let mut typed: TypedBitVec<i8_as_i4> = TypedBitVec::new();
+    typed.append_iter([-8i8, -1, 0, 7])?;
+    assert_eq!(typed.iter().collect::<Vec<i8>>(), vec![-8, -1, 0, 7], "\n11 FAIL: typed signed round trip");
+    Ok(())
+}
+
+#[test]
+fn typed_signed_positive_values() -> Result<(), String> {
+    // Positive values used to decode with the sign bit smeared in, so `i8_as_i4` read `7` back as `-1`
+    let mut i4: TypedBitVec<i8_as_i4> = TypedBitVec::new();
+    i4.append_iter([7i8, 1, 3, -8])?;
+    assert_eq!(i4.iter().collect::<Vec<i8>>(), vec![7, 1, 3, -8], "\n1 FAIL: i8_as_i4 positive values");
+    assert_eq!(i4.get(0)?, 7, "\n2 FAIL: i8_as_i4 get(0)");
+    assert_eq!(i8_as_i4::bits_to_val(0b0111), 7, "\n3 FAIL: i8_as_i4 bits_to_val(0b0111)");
+    assert_eq!(i8_as_i4::bits_to_val(0b1000), -8, "\n4 FAIL: i8_as_i4 bits_to_val(0b1000)");
+    let mut i7: TypedBitVec<i8_as_i7> = TypedBitVec::new();
+    i7.append_iter([i8_as_i7::MAX, 1, i8_as_i7::MIN])?;
+    assert_eq!(i7.iter().collect::<Vec<i8>>(), vec![63, 1, -64], "\n5 FAIL: i8_as_i7 positive values");
+    let mut i15: TypedBitVec<i16_as_i15> = TypedBitVec::new();
+    i15.append_iter([i16_as_i15::MAX, 2, -2])?;
+    assert_eq!(i15.iter().collect::<Vec<i16>>(), vec![16_383, 2, -2], "\n6 FAIL: i16_as_i15 positive values");
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use std::thread;
+use crate::*;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn variants_are_send_sync() {
+    assert_send_sync::<RawBitVec>();
+    assert_send_sync::<RawBitVecIter>();
+    assert_send_sync::<CProtoBitVec<3>>();
+    assert_send_sync::<CProtoBitVecIter<3>>();
+    assert_send_sync::<SProtoBitVec>();
+    assert_send_sync::<SProtoBitVecIter>();
+    assert_send_sync::<LProtoBitVec>();
+    assert_send_sync::<LProtoBitVecIter>();
+    assert_send_sync::<TypedBitVec<u8_as_u3>>();
+    assert_send_sync::<TypedBitVecIter<u8_as_u3>>();
+    assert_send_sync::<BitVecView>();
+    assert_send_sync::<BitVecViewIter>();
+    assert_send_sync::<TypedBitVecView<u8_as_u3>>();
+    assert_send_sync::<TypedBitVecViewIter<u8_as_u3>>();
+    assert_send_sync::<AtomicBitVec>();
+}
+
+#[test]
+fn shared_views_across_threads() -> Result<(), String> {
+    let mut bitvec: TypedBitVec<u8_as_u3> = TypedBitVec::new();
+    bitvec.append_iter((0..1000).map(|i| (i % 8) as u8))?;
+    let view = bitvec.view();
+    let sums: Vec<usize> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..4).map(|t| {
+            let part = view.slice(t*250..(t+1)*250).unwrap();
+            scope.spawn(move || part.iter().map(|val| val as usize).sum::<usize>())
+        }).collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+    let total: usize = sums.iter().sum();
+    assert_eq!(total, (0..1000).map(|i| i % 8).sum::<usize>(), "\n1 FAIL: incorrect sum across threads");
+    let mut lproto = LProtoBitVec::new(BitProto::create(5));
+    lproto.append_iter(0..32)?;
+    let moved = thread::spawn(move || lproto.into_iter().sum::<usize>()).join().unwrap();
+    assert_eq!(moved, (0..32).sum::<usize>(), "\n2 FAIL: incorrect sum after moving to thread");
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use core::num::NonZeroU8;
+use crate::*;
+
+#[test]
+fn std_type_elems() -> Result<(), String> {
+    let mut chars: TypedBitVec<char> = TypedBitVec::new();
+    chars.append_iter("Hello, ASCII!".chars().collect::<Vec<char>>())?;
+    assert_eq!(chars.iter().collect::<String>(), "Hello, ASCII!", "\n1 FAIL: char round trip");
+    let mut orderings: TypedBitVec<Ordering> = TypedBitVec::new();
+    orderings.append_iter([Ordering::Greater, Ordering::Less, Ordering::Equal])?;
+    assert_eq!(orderings.iter().collect::<Vec<Ordering>>(), vec![Ordering::Greater, Ordering::Less, Ordering::Equal], "\n2 FAIL: Ordering round trip");
+    let mut nonzero: TypedBitVec<NonZeroU8_as_u3> = TypedBitVec::new();
+    let vals: Vec<NonZeroU8> = (1..=8).map(|n| NonZeroU8::new(n).unwrap()).collect();
+    nonzero.append_iter(vals.clone())?;
+    assert_eq!(NonZeroU8_as_u3::PROTO.BITS, 3, "\n3 FAIL: NonZeroU8_as_u3 width");
+    assert_eq!(nonzero.iter().collect::<Vec<NonZeroU8>>(), vals, "\n4 FAIL: NonZeroU8 round trip");
+    let mut options: TypedBitVec<Option<u8_as_u3>> = TypedBitVec::new();
+    assert_eq!(<Option<u8_as_u3>>::PROTO.BITS, 4, "\n5 FAIL: Option width");
+    options.append_iter([Some(0u8), None, Some(7)])?;
+    assert_eq!(options.iter().collect::<Vec<Option<u8>>>(), vec![Some(0), None, Some(7)], "\n6 FAIL: Option round trip");
+    Ok(())
+}
+
+#[test]
+fn composite_elems() -> Result<(), String> {
+    type Packed = (bool, u8_as_u3, i8_as_i4);
+    assert_eq!(<Packed>::PROTO.BITS, 8, "\n1 FAIL: tuple width");
+    let mut tuples: TypedBitVec<Packed> = TypedBitVec::new();
+    tuples.append_iter([(true, 5u8, -3i8), (false, 0, 7), (true, 7, -8)])?;
+    assert_eq!(tuples.iter().collect::<Vec<(bool, u8, i8)>>(), vec![(true, 5, -3), (false, 0, 7), (true, 7, -8)], "\n2 FAIL: tuple round trip");
+    assert_val_result!("3", 0b11011011, unsafe {tuples.0.get(Packed::PROTO, 0)});
+    assert_eq!(<[u8_as_u5; 4]>::PROTO.BITS, 20, "\n4 FAIL: array width");
+    let mut arrays: TypedBitVec<[u8_as_u5; 4]> = TypedBitVec::new();
+    arrays.append_iter([[1u8, 2, 3, 31], [31, 0, 0, 16]])?;
+    assert_eq!(arrays.iter().collect::<Vec<[u8; 4]>>(), vec![[1, 2, 3, 31], [31, 0, 0, 16]], "\n5 FAIL: array round trip");
+    let mut nested: TypedBitVec<(Option<bool>, [bool; 3])> = TypedBitVec::new();
+    nested.push((None, [true, false, true]))?;
+    nested.push((Some(false), [false, true, false]))?;
+    assert_eq!(nested.iter().collect::<Vec<(Option<bool>, [bool; 3])>>(), vec![(None, [true, false, true]), (Some(false), [false, true, false])], "\n6 FAIL: nested round trip");
+    Ok(())
+}
+
+#[test]
+fn full_width_nonzero_bit_patterns() -> Result<(), String> {
+    let mut raw = LProtoBitVec::new(NonZeroU8_as_u8::PROTO);
+    raw.append_iter([0, 254])?;
+    let typed = TypedBitVec::<NonZeroU8_as_u8>::try_from(raw)?;
+    assert_eq!(typed.iter().map(NonZeroU8::get).collect::<Vec<u8>>(), vec![1, 255], "\n1 FAIL: NonZeroU8_as_u8 round trip");
+    let mut raw = LProtoBitVec::new(NonZeroU8_as_u8::PROTO);
+    raw.append_iter([254, 255])?;
+    assert_error!("2", TypedBitVec::<NonZeroU8_as_u8>::try_from(raw));
+    let mut raw = LProtoBitVec::new(NonZeroU16_as_u16::PROTO);
+    raw.append_iter([u16::MAX as usize])?;
+    assert_error!("3", TypedBitVec::<NonZeroU16_as_u16>::try_from(raw));
+    let mut raw = LProtoBitVec::new(NonZeroU8_as_u3::PROTO);
+    raw.append_iter([7])?;
+    assert_eq!(TypedBitVec::<NonZeroU8_as_u3>::try_from(raw)?.get(0)?.get(), 8, "\n4 FAIL: NonZeroU8_as_u3 all-ones pattern should be valid");
+    Ok(())
+}
+
+#[test]
+fn ordering_and_char_edge_cases() -> Result<(), String> {
+    let mut raw = LProtoBitVec::new(Ordering::PROTO);
+    raw.append_iter([0, 1, 2])?;
+    let orderings = TypedBitVec::<Ordering>::try_from(raw)?;
+    assert_eq!(orderings.iter().collect::<Vec<Ordering>>(), vec![Ordering::Less, Ordering::Equal, Ordering::Greater], "\n1 FAIL: Ordering bit patterns");
+    let mut raw = LProtoBitVec::new(Ordering::PROTO);
+    raw.append_iter([3])?;
+    assert_error!("2", TypedBitVec::<Ordering>::try_from(raw));
+    assert_eq!(char::saturating_val_to_bits('é'), 0x7f, "\n3 FAIL: saturating a non-ASCII char");
+    assert_eq!(char::saturating_val_to_bits('A'), 'A' as usize, "\n4 FAIL: saturating an ASCII char");
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::*;
+use super::xorshift;
+
+fn random_lproto(bits: usize, len: usize, state: &mut u64) -> (LProtoBitVec, Vec<usize>) {
+    let proto = BitProto::create(bits);
+    let vals: Vec<usize> = (0..len).map(|_| xorshift(state) & proto.MASK).collect();
+    let mut bitvec = LProtoBitVec::new(proto);
+    bitvec.append_iter(vals.iter().copied()).unwrap();
+    (bitvec, vals)
+}
+
+#[test]
+fn vec_parity_matches_vec() -> Result<(), String> {
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    for bits in 1..=usize::BITS as usize {
+        for len in [0usize, 1, 2, 3, 31, 64, 65, 130, 201] {
+            let (mut bitvec, mut model) = random_lproto(bits, len, &mut state);
+            bitvec.reverse();
+            model.reverse();
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n1 FAIL: reverse (bits = {}, len = {})", bits, len);
+            let val = xorshift(&mut state) & bitvec.proto().MASK;
+            let mut filled = bitvec.split_off(len / 3)?;
+            let mut filled_model = model.split_off(len / 3);
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n2 FAIL: split_off kept (bits = {}, len = {})", bits, len);
+            assert_eq!(filled.iter().collect::<Vec<usize>>(), filled_model, "\n3 FAIL: split_off returned (bits = {}, len = {})", bits, len);
+            filled.fill(val)?;
+            filled_model.fill(val);
+            assert_eq!(filled.iter().collect::<Vec<usize>>(), filled_model, "\n4 FAIL: fill (bits = {}, len = {})", bits, len);
+            bitvec.resize(len + 70, val)?;
+            model.resize(len + 70, val);
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n5 FAIL: resize grow (bits = {}, len = {})", bits, len);
+            bitvec.extend_from_within(1..(len / 2) + 1)?;
+            model.extend_from_within(1..(len / 2) + 1);
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n6 FAIL: extend_from_within (bits = {}, len = {})", bits, len);
+            let mid = len / 4 + 1;
+            bitvec.rotate_left(mid)?;
+            model.rotate_left(mid);
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n7 FAIL: rotate_left (bits = {}, len = {})", bits, len);
+            let count = model.len() - 3;
+            bitvec.rotate_right(count)?;
+            model.rotate_right(count);
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n8 FAIL: rotate_right (bits = {}, len = {})", bits, len);
+            bitvec.reverse();
+            model.reverse();
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n9 FAIL: reverse after grow (bits = {}, len = {})", bits, len);
+            assert_eq!(bitvec.first()?, model[0], "\n10 FAIL: first (bits = {}, len = {})", bits, len);
+            assert_eq!(bitvec.last()?, model[model.len() - 1], "\n11 FAIL: last (bits = {}, len = {})", bits, len);
+            bitvec.truncate(len / 2);
+            model.truncate(len / 2);
+            bitvec.resize(len / 2 + 1, 0)?;
+            model.resize(len / 2 + 1, 0);
+            assert_eq!(bitvec.iter().collect::<Vec<usize>>(), model, "\n12 FAIL: truncate + resize (bits = {}, len = {})", bits, len);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature="small_int_impls")]
+fn vec_parity_errors_and_wrappers() -> Result<(), String> {
+    let mut cproto = CProtoBitVec::<3>::new();
+    assert!(cproto.is_empty(), "\n1 FAIL: new vec not empty");
+    assert_error!("2", cproto.first());
+    assert_error!("3", cproto.last());
+    cproto.reserve(100)?;
+    assert!(cproto.cap() >= 100, "\n4 FAIL: reserve did not grow capacity");
+    cproto.grow_exact_for_additional_elements_if_needed(200)?;
+    assert!(cproto.cap() >= 200, "\n5 FAIL: grow_exact_for_additional_elements_if_needed did not grow capacity");
+    assert_error!("6", cproto.reserve(usize::MAX));
+    cproto.resize(5, 6)?;
+    assert_eq!(cproto.iter().collect::<Vec<usize>>(), vec![6, 6, 6, 6, 6], "\n7 FAIL: resize");
+    assert_error!("8", cproto.resize(6, 8));
+    assert_error!("9", cproto.fill(8));
+    assert_error!("10", cproto.split_off(6));
+    assert_error!("11", cproto.extend_from_within(3..6));
+    assert_error!("12", cproto.rotate_left(6));
+    assert_error!("13", cproto.rotate_right(6));
+    assert_eq!(cproto.split_off(5)?.len(), 0, "\n14 FAIL: split_off at len not empty");
+    let mut typed: TypedBitVec<i8_as_i4> = TypedBitVec::new();
+    typed.append_iter([-8i8, -1, 0, 7])?;
+    typed.reverse();
+    assert_eq!(typed.iter().collect::<Vec<i8>>(), vec![7, 0, -1, -8], "\n15 FAIL: typed reverse");
+    assert_eq!((typed.first()?, typed.last()?), (7, -8), "\n16 FAIL: typed first/last");
+    typed.resize(6, -3)?;
+    assert_eq!(typed.iter().collect::<Vec<i8>>(), vec![7, 0, -1, -8, -3, -3], "\n17 FAIL: typed resize");
+    assert_error!("18", typed.fill(9));
+    let mut sproto = SProtoBitVec::new(&CProtoBitVec::<5>::PROTO);
+    sproto.append_iter([1, 2, 3, 4])?;
+    let tail = BitVecLike::split_off(&mut sproto, 2)?;
+    assert_eq!(tail.iter().collect::<Vec<usize>>(), vec![3, 4], "\n19 FAIL: BitVecLike split_off");
+    let mut generic = ProtoBitVec::new(ConstProto::<7>);
+    generic.append_iter([10, 20, 30])?;
+    generic.rotate_right(1)?;
+    assert_eq!(generic.iter().collect::<Vec<usize>>(), vec![30, 10, 20], "\n20 FAIL: ProtoBitVec rotate_right");
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+fn collect_views<'a>(views: impl Iterator<Item = BitVecView<'a>>) -> Vec<Vec<usize>> {
+    views.map(|view| view.iter().collect()).collect()
+}
+
+fn owned(slices: impl Iterator<Item = &'static [usize]>) -> Vec<Vec<usize>> {
+    slices.map(|slice| slice.to_vec()).collect()
+}
+
+#[test]
+fn view_chunks_match_slices() -> Result<(), String> {
+    let model: &'static [usize] = Box::leak((0..23usize).map(|val| (val * 5) & 0b111).collect::<Vec<usize>>().into_boxed_slice());
+    let mut cproto = CProtoBitVec::<3>::new();
+    cproto.append_iter(model.iter().copied())?;
+    let view = cproto.view().slice(2..21)?;
+    let model = &model[2..21];
+    for n in 1..22 {
+        assert_eq!(collect_views(cproto.view().slice(2..21)?.chunks(n)?), owned(model.chunks(n)), "\n1 FAIL: chunks({})", n);
+        assert_eq!(collect_views(view.chunks(n)?.rev()), owned(model.chunks(n).rev()), "\n2 FAIL: chunks({}).rev()", n);
+        assert_eq!(view.chunks(n)?.len(), model.chunks(n).len(), "\n3 FAIL: chunks({}).len()", n);
+        let exact = view.chunks_exact(n)?;
+        assert_eq!(exact.remainder().iter().collect::<Vec<usize>>(), model.chunks_exact(n).remainder().to_vec(), "\n4 FAIL: chunks_exact({}).remainder()", n);
+        assert_eq!(collect_views(exact), owned(model.chunks_exact(n)), "\n5 FAIL: chunks_exact({})", n);
+        assert_eq!(collect_views(view.chunks_exact(n)?.rev()), owned(model.chunks_exact(n).rev()), "\n6 FAIL: chunks_exact({}).rev()", n);
+        assert_eq!(collect_views(view.rchunks(n)?), owned(model.rchunks(n)), "\n7 FAIL: rchunks({})", n);
+        assert_eq!(collect_views(view.rchunks(n)?.rev()), owned(model.rchunks(n).rev()), "\n8 FAIL: rchunks({}).rev()", n);
+        assert_eq!(collect_views(view.windows(n)?), owned(model.windows(n)), "\n9 FAIL: windows({})", n);
+        assert_eq!(collect_views(view.windows(n)?.rev()), owned(model.windows(n).rev()), "\n10 FAIL: windows({}).rev()", n);
+        assert_eq!(view.windows(n)?.len(), model.windows(n).len(), "\n11 FAIL: windows({}).len()", n);
+    }
+    assert_error!("12", view.chunks(0));
+    assert_error!("13", cproto.windows(0));
+    let arrays = view.array_chunks::<4>().collect::<Vec<[usize; 4]>>();
+    assert_eq!(arrays, model.chunks_exact(4).map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]]).collect::<Vec<_>>(), "\n14 FAIL: array_chunks");
+    assert_eq!(view.array_chunks::<4>().remainder().len(), 3, "\n15 FAIL: array_chunks remainder");
+    let windows = view.array_windows::<2>().rev().collect::<Vec<[usize; 2]>>();
+    assert_eq!(windows, model.windows(2).rev().map(|window| [window[0], window[1]]).collect::<Vec<_>>(), "\n16 FAIL: array_windows");
+    Ok(())
+}
+
+#[test]
+fn view_block_chunks() -> Result<(), String> {
+    for bits in [1usize, 3, 8, 13, 40, 63, 64] {
+        let proto = BitProto::create(bits);
+        let vals: Vec<usize> = (0..150usize).map(|idx| idx.wrapping_mul(0x9E37_79B9) & proto.MASK).collect();
+        let mut lproto = LProtoBitVec::new(proto);
+        lproto.append_iter(vals.iter().copied())?;
+        let view = lproto.view().slice(7..143)?;
+        let mut covered = Vec::new();
+        let mut block_count = 0;
+        for (block, range) in view.block_chunks() {
+            for idx in range.clone() {
+                let abs_bit = (idx + 7) * bits;
+                let offset = abs_bit % usize::BITS as usize;
+                assert!(offset + bits <= usize::BITS as usize, "\n1 FAIL: element {} not fully inside block (bits = {})", idx, bits);
+                assert_eq!((block >> offset) & proto.MASK, vals[idx + 7], "\n2 FAIL: block lane mismatch at {} (bits = {})", idx, bits);
+            }
+            covered.extend(range);
+            block_count += 1;
+        }
+        let expected_blocks = BitProto::calc_block_count_from_bitwise_count(proto, 143) - (7 * bits / usize::BITS as usize);
+        assert_eq!(block_count, expected_blocks, "\n3 FAIL: incorrect block count (bits = {})", bits);
+        assert_eq!(view.block_chunks().len(), expected_blocks, "\n4 FAIL: incorrect block_chunks len (bits = {})", bits);
+        let straddling = (0..136usize).filter(|idx| ((idx + 7) * bits) % 64 + bits > 64).count();
+        assert_eq!(covered.len(), 136 - straddling, "\n5 FAIL: incorrect covered element count (bits = {})", bits);
+        assert!(covered.windows(2).all(|pair| pair[0] < pair[1]), "\n6 FAIL: ranges out of order (bits = {})", bits);
+        let rev_ranges: Vec<_> = view.block_chunks().rev().map(|(_, range)| range).collect();
+        let mut fwd_ranges: Vec<_> = view.block_chunks().map(|(_, range)| range).collect();
+        fwd_ranges.reverse();
+        assert_eq!(rev_ranges, fwd_ranges, "\n7 FAIL: block_chunks rev (bits = {})", bits);
+    }
+    assert_eq!(CProtoBitVec::<5>::new().block_chunks().count(), 0, "\n8 FAIL: empty block_chunks");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature="small_int_impls")]
+fn typed_view_chunks() -> Result<(), String> {
+    let mut typed: TypedBitVec<i8_as_i4> = TypedBitVec::new();
+    typed.append_iter([-8i8, -3, 0, 2, 7, -1, 5])?;
+    let chunks = typed.chunks(3)?.map(|chunk| chunk.iter().collect::<Vec<i8>>()).collect::<Vec<_>>();
+    assert_eq!(chunks, vec![vec![-8, -3, 0], vec![2, 7, -1], vec![5]], "\n1 FAIL: typed chunks");
+    let sums = typed.windows(3)?.map(|window| window.iter().map(|val| val as i32).sum::<i32>()).collect::<Vec<i32>>();
+    assert_eq!(sums, vec![-11, -1, 9, 8, 11], "\n2 FAIL: typed windows convolution");
+    let pairs = typed.view().array_chunks::<2>().collect::<Vec<[i8; 2]>>();
+    assert_eq!(pairs, vec![[-8, -3], [0, 2], [7, -1]], "\n3 FAIL: typed array_chunks");
+    let rchunks = typed.rchunks(4)?.map(|chunk| chunk.len()).collect::<Vec<usize>>();
+    assert_eq!(rchunks, vec![4, 3], "\n4 FAIL: typed rchunks");
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::*;
+use super::xorshift;
+
+fn check_model<const N: usize>(state: &mut u64, len: usize, alphabet: usize) -> Result<(), String> {
+    let model: Vec<usize> = (0..len).map(|_| xorshift(state) % alphabet).collect();
+    let mut symbols = CProtoBitVec::<N>::new();
+    symbols.append_iter(model.iter().copied())?;
+    let matrix = WaveletMatrix::<N>::new(&symbols)?;
+    assert_eq!(matrix.len(), len, "\n1 FAIL: N {} len {} len()", N, len);
+    for (idx, symbol) in model.iter().enumerate() {
+        assert_eq!(matrix.access(idx)?, *symbol, "\n2 FAIL: N {} len {} access({})", N, len, idx);
+    }
+    assert_error!("3", matrix.access(len));
+    for symbol in 0..alphabet.min(1 << N) {
+        let positions: Vec<usize> = (0..len).filter(|idx| model[*idx] == symbol).collect();
+        for idx in (0..=len).step_by(7).chain([len]) {
+            let expected = positions.iter().filter(|pos| **pos < idx).count();
+            assert_eq!(matrix.rank(symbol, idx)?, expected, "\n4 FAIL: N {} len {} rank({}, {})", N, len, symbol, idx);
+        }
+        for (k, pos) in positions.iter().enumerate() {
+            assert_eq!(matrix.select(symbol, k), Some(*pos), "\n5 FAIL: N {} len {} select({}, {})", N, len, symbol, k);
+        }
+        assert_eq!(matrix.select(symbol, positions.len()), None, "\n6 FAIL: N {} len {} select past the last {}", N, len, symbol);
+    }
+    assert_error!("7", matrix.rank(0, len + 1));
+    for _ in 0..20 {
+        let start = xorshift(state) % (len + 1);
+        let end = start + xorshift(state) % (len + 1 - start);
+        let mut sorted = model[start..end].to_vec();
+        sorted.sort_unstable();
+        for (k, symbol) in sorted.iter().enumerate() {
+            assert_eq!(matrix.quantile(start..end, k)?, *symbol, "\n8 FAIL: N {} len {} quantile({}..{}, {})", N, len, start, end, k);
+        }
+        assert_error!("9", matrix.quantile(start..end, end - start));
+        let low = xorshift(state) % (alphabet + 1);
+        let high = low + xorshift(state) % (alphabet + 2 - low);
+        let expected = sorted.iter().filter(|symbol| (low..high).contains(*symbol)).count();
+        assert_eq!(matrix.range_freq(start..end, low..high)?, expected, "\n10 FAIL: N {} len {} range_freq({}..{}, {}..{})", N, len, start, end, low, high);
+    }
+    assert_error!("11", matrix.range_freq(0..len + 1, 0..1));
+    Ok(())
+}
+
+#[test]
+fn wavelet_matrix_matches_model() -> Result<(), String> {
+    let mut state = 0x2545_F491_4F6C_DD1D;
+    for len in [0usize, 1, 63, 700, 2000] {
+        check_model::<1>(&mut state, len, 2)?;
+        check_model::<3>(&mut state, len, 8)?;
+        check_model::<3>(&mut state, len, 3)?;
+        check_model::<8>(&mut state, len, 256)?;
+        check_model::<8>(&mut state, len, 12)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn wavelet_matrix_full_width_symbols() -> Result<(), String> {
+    let model = [usize::MAX, 0, usize::MAX - 1, 1 << 63, usize::MAX, 5];
+    let mut symbols = CProtoBitVec::<64>::new();
+    symbols.append_iter(model.iter().copied())?;
+    let matrix = WaveletMatrix::<64>::new(&symbols)?;
+    for (idx, symbol) in model.iter().enumerate() {
+        assert_eq!(matrix.access(idx)?, *symbol, "\n1 FAIL: access({})", idx);
+    }
+    assert_eq!(matrix.rank(usize::MAX, 6)?, 2, "\n2 FAIL: rank(usize::MAX, 6)");
+    assert_eq!(matrix.select(usize::MAX, 1), Some(4), "\n3 FAIL: select(usize::MAX, 1)");
+    assert_eq!(matrix.quantile(0..6, 5)?, usize::MAX, "\n4 FAIL: quantile(0..6, 5)");
+    assert_eq!(matrix.quantile(0..6, 2)?, 1 << 63, "\n5 FAIL: quantile(0..6, 2)");
+    assert_eq!(matrix.range_freq(0..6, 1..usize::MAX)?, 3, "\n6 FAIL: range_freq(0..6, 1..usize::MAX)");
+    Ok(())
+}
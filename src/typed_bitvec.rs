@@ -1,10 +1,25 @@
 use std::ops::RangeFrom;
 
 use crate::{
+    BitProto,
+    CProtoBitVec,
+    ConstGrowth,
+    LProtoBitVec,
     RawBitVec,
+    RepackMode,
+    SProtoBitVec,
+    TryFromBitVecError,
     TypedBitElem, 
     TypedBitVecDrain, 
     TypedBitVecIter,
+    TypedBitVecView,
+    TypedBitVecViewChunks,
+    TypedBitVecViewChunksExact,
+    TypedBitVecViewRChunks,
+    TypedBitVecViewWindows,
+    BitVecView,
+    BitVecViewBlockChunks,
+    TypedBitVecViewIter,
     Range,
     ManuallyDrop,
     PhantomData
@@ -38,6 +53,11 @@ impl<T: TypedBitElem> TypedBitVec<T> {
         self.0.len
     }
 
+    #[inline(always)]
+    pub fn proto(&self) -> BitProto {
+        T::PROTO
+    }
+
     #[inline(always)]
     pub fn cap(&self) -> usize {
         unsafe {self.0.cap(T::PROTO)}
@@ -50,6 +70,8 @@ impl<T: TypedBitElem> TypedBitVec<T> {
 
     #[inline(always)]
     pub fn new() -> Self {
+        // Evaluating `PROTO` here turns an invalid element width into a compile error for any code that creates the vec
+        let _ = T::PROTO;
         Self(RawBitVec::new(), PhantomData)
     }
 
@@ -58,6 +80,11 @@ impl<T: TypedBitElem> TypedBitVec<T> {
         Self(RawBitVec::with_capacity(T::PROTO, cap), PhantomData)
     }
 
+    #[inline(always)]
+    pub fn from_elem(val: T::Base, count: usize) -> Result<Self, String> {
+        Ok(Self(unsafe {RawBitVec::from_elem(T::PROTO, T::try_val_to_bits(val)?, count)?}, PhantomData))
+    }
+
     #[inline(always)]
     pub fn grow_exact_for_additional_elements_if_needed(&mut self, extra_elements: usize) -> Result<(), String> {
         unsafe {self.0.grow_exact_for_additional_elements_if_needed(T::PROTO, extra_elements)}
@@ -85,7 +112,7 @@ impl<T: TypedBitElem> TypedBitVec<T> {
 
     #[inline(always)]
     pub fn push(&mut self, val: T::Base) -> Result<(), String> {
-        unsafe {self.0.push(T::PROTO, T::val_to_bits(val))}
+        unsafe {self.0.push(T::PROTO, T::try_val_to_bits(val)?)}
     }
 
     #[inline(always)]
@@ -108,7 +135,7 @@ impl<T: TypedBitElem> TypedBitVec<T> {
 
     #[inline(always)]
     pub fn insert(&mut self, idx: usize, val: T::Base) -> Result<(), String> {
-        unsafe{self.0.insert(T::PROTO, idx, T::val_to_bits(val))}
+        unsafe{self.0.insert(T::PROTO, idx, T::try_val_to_bits(val)?)}
     }
 
     #[inline(always)]
@@ -128,14 +155,14 @@ impl<T: TypedBitElem> TypedBitVec<T> {
 
     #[inline]
     pub fn insert_iter<II, TO, ESI>(&mut self, insert_idx: usize, source: II) -> Result<(), String>
-    where II: IntoIterator<Item = TO, IntoIter = ESI>, TO: ToOwned<Owned = usize>, ESI: ExactSizeIterator + Iterator<Item = TO> {
-        unsafe {self.0.insert_iter(T::PROTO, insert_idx, source)}
+    where II: IntoIterator<Item = TO, IntoIter = ESI>, TO: ToOwned<Owned = T::Base>, ESI: ExactSizeIterator + Iterator<Item = TO> {
+        self.insert_vals_checked(insert_idx, source.into_iter().map(|val| val.to_owned()))
     }
 
     #[inline]
     pub unsafe fn insert_iter_unchecked<II, TO, ESI>(&mut self, insert_idx: usize, source: II)
-    where II: IntoIterator<Item = TO, IntoIter = ESI>, TO: ToOwned<Owned = usize>, ESI: ExactSizeIterator + Iterator<Item = TO> {
-        self.0.insert_iter_unchecked(T::PROTO, insert_idx, source)
+    where II: IntoIterator<Item = TO, IntoIter = ESI>, TO: ToOwned<Owned = T::Base>, ESI: ExactSizeIterator + Iterator<Item = TO> {
+        self.0.insert_iter_unchecked(T::PROTO, insert_idx, source.into_iter().map(|val| T::val_to_bits(val.to_owned())))
     }
 
     #[inline(always)]
@@ -218,7 +245,7 @@ impl<T: TypedBitElem> TypedBitVec<T> {
     #[inline(always)]
     pub fn append_iter<II, TO, ESI>(&mut self, source: II) -> Result<(), String>
     where II: IntoIterator<Item = TO, IntoIter = ESI>, TO: ToOwned<Owned = T::Base>, ESI: ExactSizeIterator + Iterator<Item = TO> {
-        unsafe{self.0.append_iter(T::PROTO, source.into_iter().map(|val| T::val_to_bits(val.to_owned())))}
+        self.append_vals_checked(source.into_iter().map(|val| val.to_owned()))
     }
 
     #[inline(always)]
@@ -242,7 +269,7 @@ impl<T: TypedBitElem> TypedBitVec<T> {
 
     #[inline(always)]
     pub fn replace(&mut self, idx: usize, val: T::Base) -> Result<T::Base, String> {
-        match unsafe{self.0.replace(T::PROTO, idx, T::val_to_bits(val))} {
+        match unsafe{self.0.replace(T::PROTO, idx, T::try_val_to_bits(val)?)} {
             Ok(bits) => Ok(T::bits_to_val(bits)),
             Err(e) => Err(e),
         }
@@ -255,7 +282,7 @@ impl<T: TypedBitElem> TypedBitVec<T> {
 
     #[inline(always)]
     pub fn set(&mut self, idx: usize, val: T::Base) -> Result<(), String> {
-        unsafe{self.0.set(T::PROTO, idx, T::val_to_bits(val))}
+        unsafe{self.0.set(T::PROTO, idx, T::try_val_to_bits(val)?)}
     }
 
     #[inline(always)]
@@ -268,11 +295,190 @@ impl<T: TypedBitElem> TypedBitVec<T> {
         self.0.discard_from_end(count)
     }
 
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.0.truncate(new_len)
+    }
+
+    #[inline(always)]
+    pub fn reserve(&mut self, extra_elements: usize) -> Result<(), String> {
+        unsafe {self.0.reserve(T::PROTO, extra_elements)}
+    }
+
+    #[inline(always)]
+    pub fn first(&self) -> Result<T::Base, String> {
+        Ok(T::bits_to_val(unsafe {self.0.first(T::PROTO)?}))
+    }
+
+    #[inline(always)]
+    pub fn last(&self) -> Result<T::Base, String> {
+        Ok(T::bits_to_val(unsafe {self.0.last(T::PROTO)?}))
+    }
+
+    #[inline(always)]
+    pub fn resize(&mut self, new_len: usize, val: T::Base) -> Result<(), String> {
+        unsafe {self.0.resize(T::PROTO, new_len, T::try_val_to_bits(val)?)}
+    }
+
+    #[inline(always)]
+    pub fn fill(&mut self, val: T::Base) -> Result<(), String> {
+        unsafe {self.0.fill(T::PROTO, T::try_val_to_bits(val)?)}
+    }
+
+    #[inline(always)]
+    pub fn extend_from_within(&mut self, src_range: Range<usize>) -> Result<(), String> {
+        unsafe {self.0.extend_from_within(T::PROTO, src_range)}
+    }
+
+    #[inline(always)]
+    pub fn split_off(&mut self, at: usize) -> Result<Self, String> {
+        let raw = unsafe {self.0.split_off(T::PROTO, at)?};
+        Ok(Self(raw, PhantomData))
+    }
+
+    #[inline(always)]
+    pub fn reverse(&mut self) {
+        unsafe {self.0.reverse(T::PROTO)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_left(&mut self, mid: usize) -> Result<(), String> {
+        unsafe {self.0.rotate_left(T::PROTO, mid)}
+    }
+
+    #[inline(always)]
+    pub fn rotate_right(&mut self, count: usize) -> Result<(), String> {
+        unsafe {self.0.rotate_right(T::PROTO, count)}
+    }
+
+    #[inline(always)]
+    pub fn fill_range(&mut self, range: Range<usize>, val: T::Base) -> Result<(), String> {
+        unsafe {self.0.fill_range(T::PROTO, range, T::try_val_to_bits(val)?)}
+    }
+
+    #[inline(always)]
+    pub fn repeat(&self, count: usize) -> Result<Self, String> {
+        let raw = unsafe {self.0.repeat(T::PROTO, count)?};
+        Ok(Self(raw, PhantomData))
+    }
+
     #[inline(always)]
     pub fn drain<'vec>(&'vec mut self) -> TypedBitVecDrain<'vec, T> {
         TypedBitVecDrain(self.0.drain(), PhantomData)
     }
 
+    #[inline(always)]
+    pub fn view(&self) -> TypedBitVecView<'_, T> {
+        TypedBitVecView(unsafe {self.0.view(T::PROTO)}, PhantomData)
+    }
+
+    #[inline(always)]
+    pub fn chunks(&self, chunk_len: usize) -> Result<TypedBitVecViewChunks<'_, T>, String> {
+        self.view().chunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn chunks_exact(&self, chunk_len: usize) -> Result<TypedBitVecViewChunksExact<'_, T>, String> {
+        self.view().chunks_exact(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn rchunks(&self, chunk_len: usize) -> Result<TypedBitVecViewRChunks<'_, T>, String> {
+        self.view().rchunks(chunk_len)
+    }
+
+    #[inline(always)]
+    pub fn windows(&self, window_len: usize) -> Result<TypedBitVecViewWindows<'_, T>, String> {
+        self.view().windows(window_len)
+    }
+
+    #[inline(always)]
+    pub fn block_chunks(&self) -> BitVecViewBlockChunks<'_> {
+        self.view().block_chunks()
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> TypedBitVecViewIter<'_, T> {
+        self.view().into_iter()
+    }
+
+    /// Convert every element with `map_fn` into a new [`TypedBitVec<U>`], repacking into `U::PROTO` in the same pass
+    /// 
+    /// Returns the error from [`TypedBitElem::try_val_to_bits()`] for the first mapped value that doesn't fit in `U`
+    #[inline]
+    pub fn map_into<U: TypedBitElem>(&self, map_fn: impl FnMut(T::Base) -> U::Base) -> Result<TypedBitVec<U>, String> {
+        let mut new_vec = TypedBitVec::<U>::new();
+        new_vec.append_vals_checked(self.iter().map(map_fn))?;
+        Ok(new_vec)
+    }
+
+    /// Repack every element into a [`TypedBitVec<U>`] with the same base type, handling values that don't fit in
+    /// `U::PROTO` according to `mode`
+    /// 
+    /// [`RepackMode::Truncate`] encodes with `U::val_to_bits()` and [`RepackMode::Saturate`] with
+    /// `U::saturating_val_to_bits()`
+    #[inline]
+    pub fn repack<U: TypedBitElem<Base = T::Base>>(&self, mode: RepackMode) -> Result<TypedBitVec<U>, String> {
+        let mut new_vec = TypedBitVec::<U>::new();
+        match mode {
+            RepackMode::Checked => new_vec.append_vals_checked(self.iter())?,
+            RepackMode::Truncate => unsafe {new_vec.0.append_iter(U::PROTO, self.iter().map(U::val_to_bits))?},
+            RepackMode::Saturate => unsafe {new_vec.0.append_iter(U::PROTO, self.iter().map(U::saturating_val_to_bits))?},
+        }
+        Ok(new_vec)
+    }
+
+    /// Check the bits of every element of `view` with [`TypedBitElem::check_bits()`]
+    #[inline]
+    pub(crate) fn check_elems(view: BitVecView<'_>) -> Result<(), String> {
+        view.iter().enumerate().try_for_each(|(idx, bits)| T::check_bits(bits).map_err(|err| {
+            format!("element at index {} is not a valid {}: {}", idx, core::any::type_name::<T>(), err)
+        }))
+    }
+
+    /// Append every value with [`TypedBitElem::try_val_to_bits()`], undoing the whole append if any value doesn't fit
+    #[inline]
+    pub(crate) fn append_vals_checked<I>(&mut self, iter: I) -> Result<(), String>
+    where I: ExactSizeIterator<Item = T::Base> {
+        let start_len = self.0.len;
+        let mut first_err = None;
+        unsafe {self.0.append_iter(T::PROTO, iter.map(|val| T::try_val_to_bits(val).unwrap_or_else(|err| {
+            first_err.get_or_insert(err);
+            0
+        })))?};
+        match first_err {
+            Some(err) => {
+                self.0.len = start_len;
+                Err(err)
+            },
+            None => Ok(())
+        }
+    }
+
+    /// Insert every value with [`TypedBitElem::try_val_to_bits()`], undoing the whole insert if any value doesn't fit
+    #[inline]
+    pub(crate) fn insert_vals_checked<I>(&mut self, insert_idx: usize, iter: I) -> Result<(), String>
+    where I: ExactSizeIterator<Item = T::Base> {
+        let count = iter.len();
+        let mut first_err = None;
+        unsafe {self.0.insert_iter(T::PROTO, insert_idx, iter.map(|val| T::try_val_to_bits(val).unwrap_or_else(|err| {
+            first_err.get_or_insert(err);
+            0
+        })))?};
+        match first_err {
+            Some(err) => {
+                unsafe {self.0.remove_range_unchecked(T::PROTO, insert_idx..insert_idx+count)};
+                Err(err)
+            },
+            None => Ok(())
+        }
+    }
+
     #[inline(always)]
     pub unsafe fn into_raw(self) -> RawBitVec {
         let nodrop_self = ManuallyDrop::new(self);
@@ -282,6 +488,13 @@ impl<T: TypedBitElem> TypedBitVec<T> {
             true_cap: nodrop_self.0.true_cap 
         }
     }
+
+    /// ## Safety
+    /// `raw` must have only ever been used with a [`BitProto`] of the same bit width as `T::PROTO`
+    #[inline(always)]
+    pub unsafe fn from_raw(raw: RawBitVec) -> Self {
+        Self(raw, PhantomData)
+    }
 }
 
 impl<T: TypedBitElem> IntoIterator for TypedBitVec<T> {
@@ -298,4 +511,43 @@ impl<T: TypedBitElem> IntoIterator for TypedBitVec<T> {
 impl<T: TypedBitElem> Drop for TypedBitVec<T> {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVec::drop() will take care of the allocation */}
-}
\ No newline at end of file
+}
+
+impl<T: TypedBitElem> TryFrom<LProtoBitVec> for TypedBitVec<T> {
+    type Error = TryFromBitVecError<LProtoBitVec>;
+
+    #[inline(always)]
+    fn try_from(bitvec: LProtoBitVec) -> Result<Self, Self::Error> {
+        let check = BitProto::check_same_width(bitvec.proto, T::PROTO).and_then(|_| Self::check_elems(bitvec.view()));
+        if let Err(err) = check {
+            return Err(TryFromBitVecError::new(bitvec, err));
+        }
+        Ok(unsafe {Self::from_raw(bitvec.into_raw())})
+    }
+}
+
+impl<T: TypedBitElem> TryFrom<SProtoBitVec> for TypedBitVec<T> {
+    type Error = TryFromBitVecError<SProtoBitVec>;
+
+    #[inline(always)]
+    fn try_from(bitvec: SProtoBitVec) -> Result<Self, Self::Error> {
+        let check = BitProto::check_same_width(*bitvec.proto, T::PROTO).and_then(|_| Self::check_elems(bitvec.view()));
+        if let Err(err) = check {
+            return Err(TryFromBitVecError::new(bitvec, err));
+        }
+        Ok(unsafe {Self::from_raw(bitvec.into_raw())})
+    }
+}
+
+impl<const BIT_WIDTH: usize, G: ConstGrowth, T: TypedBitElem> TryFrom<CProtoBitVec<BIT_WIDTH, G>> for TypedBitVec<T> {
+    type Error = TryFromBitVecError<CProtoBitVec<BIT_WIDTH, G>>;
+
+    #[inline(always)]
+    fn try_from(bitvec: CProtoBitVec<BIT_WIDTH, G>) -> Result<Self, Self::Error> {
+        let check = BitProto::check_same_width(CProtoBitVec::<BIT_WIDTH>::PROTO, T::PROTO).and_then(|_| Self::check_elems(bitvec.view()));
+        if let Err(err) = check {
+            return Err(TryFromBitVecError::new(bitvec, err));
+        }
+        Ok(unsafe {Self::from_raw(bitvec.into_raw())})
+    }
+}
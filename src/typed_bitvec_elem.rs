@@ -1,7 +1,12 @@
+#[cfg(feature="small_int_impls")]
+use core::num::{NonZeroU8, NonZeroU16};
+
 use crate::{
     BitUtil, 
     BitProto
 };
+#[cfg(feature="small_int_impls")]
+use crate::Ordering;
 
 
 pub unsafe trait TypedBitElem {
@@ -9,6 +14,28 @@ pub unsafe trait TypedBitElem {
     const PROTO: BitProto;
     fn bits_to_val(bits: usize) -> Self::Base;
     fn val_to_bits(val: Self::Base) -> usize;
+    /// Like `val_to_bits()`, but returns an error instead of masking or clamping a value that doesn't fit
+    /// 
+    /// The default implementation assumes every value of `Self::Base` fits
+    #[inline(always)]
+    fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+        Ok(Self::val_to_bits(val))
+    }
+    /// Like `val_to_bits()`, but replaces a value that doesn't fit with the closest value that does
+    /// 
+    /// The default implementation falls back to `val_to_bits()`
+    #[inline(always)]
+    fn saturating_val_to_bits(val: Self::Base) -> usize {
+        Self::val_to_bits(val)
+    }
+    /// Returns an error if `bits` is not a valid encoding of any value, meaning `bits_to_val()` would panic or return
+    /// a value outside the type's range
+    /// 
+    /// The default implementation accepts every bit pattern
+    #[inline(always)]
+    fn check_bits(_bits: usize) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 macro_rules! impl_bitelem_unsigned {
@@ -26,6 +53,17 @@ macro_rules! impl_bitelem_unsigned {
             fn val_to_bits(val: Self::Base) -> usize {
                 (val as usize) & Self::PROTO.MASK
             }
+            #[inline(always)]
+            fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+                match (val as usize) > Self::PROTO.MASK {
+                    true => Err(format!("value cannot be represented by {}: {}", stringify!($TYPE), val)),
+                    false => Ok(val as usize)
+                }
+            }
+            #[inline(always)]
+            fn saturating_val_to_bits(val: Self::Base) -> usize {
+                (val as usize).min(Self::PROTO.MASK)
+            }
         }
         impl $TYPE {
             pub const MIN: $BASE = 0;
@@ -53,6 +91,17 @@ macro_rules! impl_bitelem_signed {
                 neg_bit >>= Self::Base::BITS as usize - Self::PROTO.BITS;
                 (neg_bit | (val as usize)) & Self::PROTO.MASK
             }
+            #[inline(always)]
+            fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+                match !(Self::MIN..=Self::MAX).contains(&val) {
+                    true => Err(format!("value cannot be represented by {}: {}", stringify!($TYPE), val)),
+                    false => Ok(Self::val_to_bits(val))
+                }
+            }
+            #[inline(always)]
+            fn saturating_val_to_bits(val: Self::Base) -> usize {
+                Self::val_to_bits(Self::clamp_val(val))
+            }
         }
         impl $TYPE {
             pub(crate) const TOP_BIT: usize = 1 << (Self::PROTO.BITS - 1);
@@ -216,3 +265,351 @@ impl_bitelem_signed!(
     (i64, i64_as_i62, 62),
     (i64, i64_as_i63, 63)
 );
+
+#[cfg(feature="small_int_impls")]
+macro_rules! impl_bitelem_nonzero {
+    ($(($BASE:ty, $PRIM:ty, $TYPE:ident, $BITS:expr)),+) => {$(
+        /// Stores a non-zero value `n` as `n - 1`, so the range `1..=2^BITS` fits in `BITS` bits (`1..=2^BITS - 1` when
+        /// `BITS` is the full width of the base type, leaving the all-ones bit pattern unused)
+        #[allow(non_camel_case_types)]
+        pub struct $TYPE;
+        unsafe impl TypedBitElem for $TYPE {
+            type Base = $BASE;
+            const PROTO: BitProto = BitProto::create($BITS);
+            #[inline(always)]
+            fn bits_to_val(bits: usize) -> Self::Base {
+                <$BASE>::MIN.saturating_add(bits as $PRIM)
+            }
+            #[inline(always)]
+            fn val_to_bits(val: Self::Base) -> usize {
+                ((val.get() - 1) as usize) & Self::PROTO.MASK
+            }
+            #[inline(always)]
+            fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+                match ((val.get() - 1) as usize) > Self::PROTO.MASK {
+                    true => Err(format!("value cannot be represented by {}: {}", stringify!($TYPE), val)),
+                    false => Ok((val.get() - 1) as usize)
+                }
+            }
+            #[inline(always)]
+            fn saturating_val_to_bits(val: Self::Base) -> usize {
+                ((val.get() - 1) as usize).min(Self::PROTO.MASK)
+            }
+            #[inline(always)]
+            fn check_bits(bits: usize) -> Result<(), String> {
+                match bits > <$PRIM>::MAX as usize - 1 {
+                    true => Err(format!("bit pattern does not encode a {}: {}", stringify!($TYPE), bits)),
+                    false => Ok(())
+                }
+            }
+        }
+    )+};
+}
+
+#[cfg(feature="small_int_impls")]
+impl_bitelem_nonzero!(
+    (NonZeroU8, u8, NonZeroU8_as_u1, 1),
+    (NonZeroU8, u8, NonZeroU8_as_u2, 2),
+    (NonZeroU8, u8, NonZeroU8_as_u3, 3),
+    (NonZeroU8, u8, NonZeroU8_as_u4, 4),
+    (NonZeroU8, u8, NonZeroU8_as_u5, 5),
+    (NonZeroU8, u8, NonZeroU8_as_u6, 6),
+    (NonZeroU8, u8, NonZeroU8_as_u7, 7),
+    (NonZeroU8, u8, NonZeroU8_as_u8, 8),
+    (NonZeroU16, u16, NonZeroU16_as_u9, 9),
+    (NonZeroU16, u16, NonZeroU16_as_u10, 10),
+    (NonZeroU16, u16, NonZeroU16_as_u11, 11),
+    (NonZeroU16, u16, NonZeroU16_as_u12, 12),
+    (NonZeroU16, u16, NonZeroU16_as_u13, 13),
+    (NonZeroU16, u16, NonZeroU16_as_u14, 14),
+    (NonZeroU16, u16, NonZeroU16_as_u15, 15),
+    (NonZeroU16, u16, NonZeroU16_as_u16, 16)
+);
+
+/// ASCII characters only (7 bits), any other `char` is masked down to its lowest 7 bits by `val_to_bits()` or replaced
+/// with `'\x7f'` by `saturating_val_to_bits()`
+#[cfg(feature="small_int_impls")]
+unsafe impl TypedBitElem for char {
+    type Base = char;
+    const PROTO: BitProto = BitProto::create(7);
+    #[inline(always)]
+    fn bits_to_val(bits: usize) -> Self::Base {
+        ((bits & Self::PROTO.MASK) as u8) as char
+    }
+    #[inline(always)]
+    fn val_to_bits(val: Self::Base) -> usize {
+        (val as usize) & Self::PROTO.MASK
+    }
+    #[inline(always)]
+    fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+        match val.is_ascii() {
+            true => Ok(val as usize),
+            false => Err(format!("char is not ASCII: {:?}", val))
+        }
+    }
+    #[inline(always)]
+    fn saturating_val_to_bits(val: Self::Base) -> usize {
+        (val as usize).min(Self::PROTO.MASK)
+    }
+}
+
+/// Stored in 2 bits as `Less = 0`, `Equal = 1`, `Greater = 2`
+#[cfg(feature="small_int_impls")]
+unsafe impl TypedBitElem for Ordering {
+    type Base = Ordering;
+    const PROTO: BitProto = BitProto::create(2);
+    #[inline(always)]
+    fn bits_to_val(bits: usize) -> Self::Base {
+        match bits {
+            0 => Ordering::Less,
+            1 => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+    #[inline(always)]
+    fn val_to_bits(val: Self::Base) -> usize {
+        (val as i8 + 1) as usize
+    }
+    #[inline(always)]
+    fn check_bits(bits: usize) -> Result<(), String> {
+        match bits > 2 {
+            true => Err(format!("bit pattern does not encode an Ordering: {}", bits)),
+            false => Ok(())
+        }
+    }
+}
+
+/// Stores `E` shifted up by one bit, with the lowest bit set when the value is `Some`
+/// 
+/// Using an `E` that is already `usize::BITS` wide fails to compile when `PROTO` is evaluated
+unsafe impl<E: TypedBitElem> TypedBitElem for Option<E> {
+    type Base = Option<E::Base>;
+    const PROTO: BitProto = BitProto::create(E::PROTO.BITS + 1);
+    #[inline(always)]
+    fn bits_to_val(bits: usize) -> Self::Base {
+        match bits & 1 {
+            1 => Some(E::bits_to_val((bits >> 1) & E::PROTO.MASK)),
+            _ => None
+        }
+    }
+    #[inline(always)]
+    fn val_to_bits(val: Self::Base) -> usize {
+        match val {
+            Some(val) => ((E::val_to_bits(val) & E::PROTO.MASK) << 1) | 1,
+            None => 0
+        }
+    }
+    #[inline(always)]
+    fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+        match val {
+            Some(val) => Ok((E::try_val_to_bits(val)? << 1) | 1),
+            None => Ok(0)
+        }
+    }
+    #[inline(always)]
+    fn check_bits(bits: usize) -> Result<(), String> {
+        match bits & 1 {
+            1 => E::check_bits((bits >> 1) & E::PROTO.MASK),
+            _ => Ok(())
+        }
+    }
+}
+
+/// Stores every element of the array back-to-back, element `0` in the lowest bits
+/// 
+/// An array whose combined width exceeds `usize::BITS` (or `N == 0`) fails to compile when `PROTO` is evaluated
+unsafe impl<E: TypedBitElem, const N: usize> TypedBitElem for [E; N] {
+    type Base = [E::Base; N];
+    const PROTO: BitProto = BitProto::create(E::PROTO.BITS * N);
+    #[inline(always)]
+    fn bits_to_val(bits: usize) -> Self::Base {
+        core::array::from_fn(|idx| E::bits_to_val((bits >> (idx * E::PROTO.BITS)) & E::PROTO.MASK))
+    }
+    #[inline(always)]
+    fn val_to_bits(val: Self::Base) -> usize {
+        let mut bits = 0;
+        for (idx, elem) in val.into_iter().enumerate() {
+            bits |= (E::val_to_bits(elem) & E::PROTO.MASK) << (idx * E::PROTO.BITS);
+        }
+        bits
+    }
+    #[inline(always)]
+    fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+        let mut bits = 0;
+        for (idx, elem) in val.into_iter().enumerate() {
+            bits |= E::try_val_to_bits(elem)? << (idx * E::PROTO.BITS);
+        }
+        Ok(bits)
+    }
+    #[inline(always)]
+    fn check_bits(bits: usize) -> Result<(), String> {
+        for idx in 0..N {
+            E::check_bits((bits >> (idx * E::PROTO.BITS)) & E::PROTO.MASK)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_bitelem_tuple {
+    ($(($($ELEM:ident $IDX:tt),+)),+) => {$(
+        /// Stores every field of the tuple back-to-back, the first field in the lowest bits
+        /// 
+        /// A tuple whose combined width exceeds `usize::BITS` fails to compile when `PROTO` is evaluated
+        unsafe impl<$($ELEM: TypedBitElem),+> TypedBitElem for ($($ELEM,)+) {
+            type Base = ($($ELEM::Base,)+);
+            const PROTO: BitProto = BitProto::create(0 $(+ $ELEM::PROTO.BITS)+);
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn bits_to_val(bits: usize) -> Self::Base {
+                let mut offset = 0;
+                ($({
+                    let field = $ELEM::bits_to_val((bits >> offset) & $ELEM::PROTO.MASK);
+                    offset += $ELEM::PROTO.BITS;
+                    field
+                },)+)
+            }
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn val_to_bits(val: Self::Base) -> usize {
+                let mut bits = 0;
+                let mut offset = 0;
+                $(
+                    bits |= ($ELEM::val_to_bits(val.$IDX) & $ELEM::PROTO.MASK) << offset;
+                    offset += $ELEM::PROTO.BITS;
+                )+
+                bits
+            }
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+                let mut bits = 0;
+                let mut offset = 0;
+                $(
+                    bits |= $ELEM::try_val_to_bits(val.$IDX)? << offset;
+                    offset += $ELEM::PROTO.BITS;
+                )+
+                Ok(bits)
+            }
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn check_bits(bits: usize) -> Result<(), String> {
+                let mut offset = 0;
+                $(
+                    $ELEM::check_bits((bits >> offset) & $ELEM::PROTO.MASK)?;
+                    offset += $ELEM::PROTO.BITS;
+                )+
+                Ok(())
+            }
+        }
+    )+};
+}
+
+impl_bitelem_tuple!(
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+);
+
+/// ## `Ranged`: "Ranged Integer Element"  
+/// A [`TypedBitElem`] for `i64` values in the inclusive range `MIN..=MAX`, stored as `val - MIN` in the fewest bits
+/// that can hold every value in the range (for example `Ranged<1900, 2155>` fits in 8 bits)
+/// 
+/// `val_to_bits()` and `saturating_val_to_bits()` clamp values outside the range to `MIN` or `MAX` (so the stored bits
+/// are always a valid element), use [`TypedBitElem::try_val_to_bits()`] to reject them instead
+pub struct Ranged<const MIN: i64, const MAX: i64>;
+
+impl<const MIN: i64, const MAX: i64> Ranged<MIN, MAX> {
+    pub const MIN: i64 = MIN;
+    pub const MAX: i64 = MAX;
+    const SPAN: usize = {
+        assert!(MIN <= MAX, "Ranged<MIN, MAX> requires MIN <= MAX");
+        assert!((MAX as i128 - MIN as i128) <= usize::MAX as i128, "Ranged<MIN, MAX> span does not fit in usize");
+        MAX.wrapping_sub(MIN) as u64 as usize
+    };
+}
+
+unsafe impl<const MIN: i64, const MAX: i64> TypedBitElem for Ranged<MIN, MAX> {
+    type Base = i64;
+    const PROTO: BitProto = BitProto::create_from_state_count(Self::SPAN.saturating_add(1));
+    #[inline(always)]
+    fn bits_to_val(bits: usize) -> Self::Base {
+        MIN.wrapping_add(bits as u64 as i64)
+    }
+    #[inline(always)]
+    fn val_to_bits(val: Self::Base) -> usize {
+        Self::saturating_val_to_bits(val)
+    }
+    #[inline(always)]
+    fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+        match (MIN..=MAX).contains(&val) {
+            true => Ok(val.wrapping_sub(MIN) as u64 as usize),
+            false => Err(format!("value out of range for Ranged<{}, {}>: {}", MIN, MAX, val))
+        }
+    }
+    #[inline(always)]
+    fn saturating_val_to_bits(val: Self::Base) -> usize {
+        val.clamp(MIN, MAX).wrapping_sub(MIN) as u64 as usize
+    }
+    #[inline(always)]
+    fn check_bits(bits: usize) -> Result<(), String> {
+        match bits > Self::SPAN {
+            true => Err(format!("bit pattern out of range for Ranged<{}, {}>: {}", MIN, MAX, bits)),
+            false => Ok(())
+        }
+    }
+}
+
+/// Declares a fieldless enum and implements [`TypedBitElem`] for it, stored in the fewest bits that can hold every variant
+/// 
+/// Variants are numbered `0..N` in declaration order (explicit discriminants are not supported), and the enum also gets
+/// `TryFrom<usize>` (with `Error = String`) that returns an error for bit patterns that don't match a variant.
+/// `check_bits()` rejects the same patterns, so the `TryFrom` conversions into [`TypedBitVec`](crate::TypedBitVec) refuse
+/// vectors holding them and `bits_to_val()` can only panic if they were written through an unsafe API. Attributes
+/// (like `#[derive(...)]`) and visibility are passed through, for example
+/// `bit_enum!{ #[derive(Debug, Clone, Copy, PartialEq, Eq)] pub enum State { Idle, Running, Done } }`
+/// declares `State` stored in 2 bits
+#[macro_export]
+macro_rules! bit_enum {
+    ($(#[$META:meta])* $VIS:vis enum $NAME:ident { $($(#[$VAR_META:meta])* $VARIANT:ident),+ $(,)? }) => {
+        $(#[$META])*
+        $VIS enum $NAME {
+            $($(#[$VAR_META])* $VARIANT),+
+        }
+        impl $NAME {
+            pub const VARIANT_COUNT: usize = [$(stringify!($VARIANT)),+].len();
+        }
+        unsafe impl $crate::TypedBitElem for $NAME {
+            type Base = $NAME;
+            const PROTO: $crate::BitProto = $crate::BitProto::create_from_state_count(Self::VARIANT_COUNT);
+            #[inline(always)]
+            fn bits_to_val(bits: usize) -> Self::Base {
+                match <Self as ::core::convert::TryFrom<usize>>::try_from(bits) {
+                    ::core::result::Result::Ok(val) => val,
+                    ::core::result::Result::Err(err) => ::core::panic!("{}", err)
+                }
+            }
+            #[inline(always)]
+            fn val_to_bits(val: Self::Base) -> usize {
+                val as usize
+            }
+            #[inline(always)]
+            fn check_bits(bits: usize) -> ::core::result::Result<(), ::std::string::String> {
+                <Self as ::core::convert::TryFrom<usize>>::try_from(bits).map(|_| ())
+            }
+        }
+        impl ::core::convert::TryFrom<usize> for $NAME {
+            type Error = ::std::string::String;
+            #[inline(always)]
+            fn try_from(bits: usize) -> ::core::result::Result<Self, Self::Error> {
+                $(if bits == $NAME::$VARIANT as usize {
+                    return ::core::result::Result::Ok($NAME::$VARIANT);
+                })+
+                ::core::result::Result::Err(::std::format!("bit pattern does not match any variant of {}: {}", stringify!($NAME), bits))
+            }
+        }
+    };
+}
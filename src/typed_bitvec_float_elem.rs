@@ -0,0 +1,256 @@
+use crate::{
+    BitProto,
+    TypedBitElem
+};
+
+/// ## `Fixed`: "Unsigned Fixed-Point Element"
+/// A [`TypedBitElem`] for non-negative `f32` values stored as an unsigned fixed-point number with `I` integer bits
+/// and `F` fractional bits (`I + F` bits total), for example `Fixed<4, 4>` stores `0.0..=15.9375` in steps of `0.0625`
+///
+/// Values are rounded to the nearest step (halfway values round away from zero). `val_to_bits()` saturates values
+/// outside `MIN..=MAX` to the nearest end of the range and stores `NaN` as `0.0`, use
+/// [`TypedBitElem::try_val_to_bits()`] to reject them instead
+///
+/// `I + F` must be between `1` and `usize::BITS`, otherwise it fails to compile when `PROTO` is evaluated
+pub struct Fixed<const I: usize, const F: usize>;
+
+impl<const I: usize, const F: usize> Fixed<I, F> {
+    const SCALE: f64 = pow_2(F);
+    pub const MIN: f32 = 0.0;
+    pub const MAX: f32 = (Self::PROTO.MASK as f64 / Self::SCALE) as f32;
+}
+
+unsafe impl<const I: usize, const F: usize> TypedBitElem for Fixed<I, F> {
+    type Base = f32;
+    const PROTO: BitProto = BitProto::create(I + F);
+    #[inline(always)]
+    fn bits_to_val(bits: usize) -> Self::Base {
+        (bits as f64 / Self::SCALE) as f32
+    }
+    #[inline(always)]
+    fn val_to_bits(val: Self::Base) -> usize {
+        ((val as f64 * Self::SCALE).round() as usize).min(Self::PROTO.MASK)
+    }
+    #[inline(always)]
+    fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+        let steps = (val as f64 * Self::SCALE).round();
+        match steps >= 0.0 && steps <= Self::PROTO.MASK as f64 {
+            true => Ok((steps as usize).min(Self::PROTO.MASK)),
+            false => Err(format!("value cannot be represented by Fixed<{}, {}>: {}", I, F, val))
+        }
+    }
+}
+
+/// ## `SFixed`: "Signed Fixed-Point Element"
+/// A [`TypedBitElem`] for `f32` values stored as a two's complement fixed-point number with `I` integer bits
+/// (including the sign bit) and `F` fractional bits (`I + F` bits total), for example `SFixed<4, 4>` stores
+/// `-8.0..=7.9375` in steps of `0.0625`
+///
+/// Values are rounded to the nearest step (halfway values round away from zero). `val_to_bits()` saturates values
+/// outside `MIN..=MAX` to the nearest end of the range and stores `NaN` as `0.0`, use
+/// [`TypedBitElem::try_val_to_bits()`] to reject them instead
+///
+/// `I + F` must be between `1` and `usize::BITS`, otherwise it fails to compile when `PROTO` is evaluated
+pub struct SFixed<const I: usize, const F: usize>;
+
+impl<const I: usize, const F: usize> SFixed<I, F> {
+    const SCALE: f64 = pow_2(F);
+    const MIN_STEPS: isize = BitProto::bits_to_signed(Self::PROTO, Self::PROTO.MASK ^ (Self::PROTO.MASK >> 1));
+    const MAX_STEPS: isize = (Self::PROTO.MASK >> 1) as isize;
+    pub const MIN: f32 = (Self::MIN_STEPS as f64 / Self::SCALE) as f32;
+    pub const MAX: f32 = (Self::MAX_STEPS as f64 / Self::SCALE) as f32;
+}
+
+unsafe impl<const I: usize, const F: usize> TypedBitElem for SFixed<I, F> {
+    type Base = f32;
+    const PROTO: BitProto = BitProto::create(I + F);
+    #[inline(always)]
+    fn bits_to_val(bits: usize) -> Self::Base {
+        (BitProto::bits_to_signed(Self::PROTO, bits) as f64 / Self::SCALE) as f32
+    }
+    #[inline(always)]
+    fn val_to_bits(val: Self::Base) -> usize {
+        let steps = ((val as f64 * Self::SCALE).round() as isize).clamp(Self::MIN_STEPS, Self::MAX_STEPS);
+        (steps as usize) & Self::PROTO.MASK
+    }
+    #[inline(always)]
+    fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+        let steps = (val as f64 * Self::SCALE).round();
+        match steps >= Self::MIN_STEPS as f64 && steps <= Self::MAX_STEPS as f64 {
+            true => Ok(Self::val_to_bits(val)),
+            false => Err(format!("value cannot be represented by SFixed<{}, {}>: {}", I, F, val))
+        }
+    }
+}
+
+const fn pow_2(exp: usize) -> f64 {
+    let mut val = 1.0;
+    let mut i = 0;
+    while i < exp {
+        val *= 2.0;
+        i += 1;
+    }
+    val
+}
+
+/// Layout of a small IEEE-754 style binary float with one sign bit, `EXP_BITS` exponent bits and `MAN_BITS`
+/// mantissa bits, converted to and from `f32` using round-to-nearest, ties-to-even
+#[allow(non_snake_case)]
+#[derive(Clone, Copy)]
+struct MiniFloat {
+    EXP_BITS: u32,
+    MAN_BITS: u32,
+    /// `false` for formats that reuse the all-ones exponent for normal values, keeping only the all-ones
+    /// pattern as `NaN` (the OCP fp8 E4M3 layout)
+    HAS_INF: bool,
+}
+
+impl MiniFloat {
+    #[inline(always)]
+    const fn bias(self) -> i32 {
+        (1 << (self.EXP_BITS - 1)) - 1
+    }
+
+    #[inline(always)]
+    const fn exp_mask(self) -> usize {
+        (1 << self.EXP_BITS) - 1
+    }
+
+    #[inline(always)]
+    const fn man_mask(self) -> usize {
+        (1 << self.MAN_BITS) - 1
+    }
+
+    #[inline(always)]
+    const fn max_finite(self) -> usize {
+        match self.HAS_INF {
+            true => (self.exp_mask() << self.MAN_BITS) - 1,
+            false => ((self.exp_mask() << self.MAN_BITS) | self.man_mask()) - 1,
+        }
+    }
+
+    /// Returns the encoded bits and whether `val` overflowed the largest finite value
+    /// (to infinity, or saturated to the largest finite value for formats without infinity)
+    fn encode(self, val: f32) -> (usize, bool) {
+        let f32_bits = val.to_bits();
+        let sign = ((f32_bits >> 31) as usize) << (self.EXP_BITS + self.MAN_BITS);
+        let exp_all_ones = self.exp_mask() << self.MAN_BITS;
+        if val.is_nan() {
+            return (sign | exp_all_ones | self.man_mask(), false);
+        }
+        if val.is_infinite() {
+            return match self.HAS_INF {
+                true => (sign | exp_all_ones, false),
+                false => (sign | self.max_finite(), true),
+            };
+        }
+        let f32_exp = ((f32_bits >> 23) & 0xFF) as i32;
+        let mut sig = (f32_bits & 0x7F_FFFF) as u64;
+        let mut exp = match f32_exp {
+            0 => -126,
+            _ => {
+                sig |= 1 << 23;
+                f32_exp - 127
+            }
+        };
+        if sig == 0 {
+            return (sign, false);
+        }
+        while sig & (1 << 23) == 0 {
+            sig <<= 1;
+            exp -= 1;
+        }
+        let target_exp = exp.max(1 - self.bias());
+        let shift = (23 - self.MAN_BITS) as i32 + (target_exp - exp);
+        let rounded = match shift > 40 {
+            true => 0,
+            false => {
+                let kept = sig >> shift;
+                let rem = sig & ((1 << shift) - 1);
+                let half = 1 << (shift - 1);
+                match rem > half || (rem == half && kept & 1 == 1) {
+                    true => kept + 1,
+                    false => kept,
+                }
+            }
+        };
+        // For normal values `rounded` still holds the implicit leading bit, which carries into the exponent field
+        let encoded = ((((target_exp + self.bias() - 1) as u64) << self.MAN_BITS) + rounded) as usize;
+        match encoded > self.max_finite() {
+            true => match self.HAS_INF {
+                true => (sign | exp_all_ones, true),
+                false => (sign | self.max_finite(), true),
+            },
+            false => (sign | encoded, false),
+        }
+    }
+
+    fn decode(self, bits: usize) -> f32 {
+        let negative = (bits >> (self.EXP_BITS + self.MAN_BITS)) & 1 == 1;
+        let exp = (bits >> self.MAN_BITS) & self.exp_mask();
+        let man = bits & self.man_mask();
+        let mag = if exp == self.exp_mask() && (self.HAS_INF || man == self.man_mask()) {
+            match self.HAS_INF && man == 0 {
+                true => f64::INFINITY,
+                false => f64::NAN,
+            }
+        } else if exp == 0 {
+            man as f64 * 2f64.powi(1 - self.bias() - self.MAN_BITS as i32)
+        } else {
+            (man | (1 << self.MAN_BITS)) as f64 * 2f64.powi(exp as i32 - self.bias() - self.MAN_BITS as i32)
+        };
+        match negative {
+            true => -mag as f32,
+            false => mag as f32,
+        }
+    }
+}
+
+macro_rules! impl_bitelem_minifloat {
+    ($(($TYPE:ident, $EXP_BITS:expr, $MAN_BITS:expr, $HAS_INF:expr, $NAME:literal, $DOC:literal)),+) => {$(
+        #[doc = $DOC]
+        ///
+        /// Converted from `f32` by rounding to the nearest representable value (ties to even), values too small for the
+        /// format flush to a signed zero and `NaN` is kept as `NaN`. `val_to_bits()` stores values too large for the
+        /// format as infinity, or as `MAX`/`MIN` for formats without infinity, use [`TypedBitElem::try_val_to_bits()`]
+        /// to reject them instead
+        #[allow(non_camel_case_types)]
+        pub struct $TYPE;
+        impl $TYPE {
+            const FORMAT: MiniFloat = MiniFloat { EXP_BITS: $EXP_BITS, MAN_BITS: $MAN_BITS, HAS_INF: $HAS_INF };
+            /// The largest finite value, `MIN` is its negation
+            pub const MAX: f32 = {
+                let format = Self::FORMAT;
+                let max_exp = (format.max_finite() >> format.MAN_BITS) as i32 - format.bias();
+                let max_man = (format.max_finite() & format.man_mask()) | (1 << format.MAN_BITS);
+                (max_man as f64 * pow_2((max_exp - format.MAN_BITS as i32) as usize)) as f32
+            };
+            pub const MIN: f32 = -Self::MAX;
+        }
+        unsafe impl TypedBitElem for $TYPE {
+            type Base = f32;
+            const PROTO: BitProto = BitProto::create(1 + $EXP_BITS + $MAN_BITS);
+            #[inline(always)]
+            fn bits_to_val(bits: usize) -> Self::Base {
+                Self::FORMAT.decode(bits)
+            }
+            #[inline(always)]
+            fn val_to_bits(val: Self::Base) -> usize {
+                Self::FORMAT.encode(val).0
+            }
+            #[inline(always)]
+            fn try_val_to_bits(val: Self::Base) -> Result<usize, String> {
+                match Self::FORMAT.encode(val) {
+                    (_, true) => Err(format!("value cannot be represented by {}: {}", $NAME, val)),
+                    (bits, false) => Ok(bits)
+                }
+            }
+        }
+    )+};
+}
+
+impl_bitelem_minifloat!(
+    (f8_e4m3, 4, 3, false, "fp8 E4M3", "8-bit float with 4 exponent bits and 3 mantissa bits (OCP `E4M3FN`: no infinity, `MAX = 448.0`)"),
+    (f8_e5m2, 5, 2, true, "fp8 E5M2", "8-bit float with 5 exponent bits and 2 mantissa bits (OCP `E5M2`: has infinity, `MAX = 57344.0`)"),
+    (bf16, 8, 7, true, "bfloat16", "16-bit `bfloat16`, the top half of an `f32` (same exponent range, 7 mantissa bits)")
+);
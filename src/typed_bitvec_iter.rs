@@ -1,4 +1,6 @@
 use crate::{
+    NonZeroUsize,
+    BitVecViewIter,
     RawBitVecIter,
     RawBitVecDrain,
     TypedBitElem,
@@ -23,6 +25,21 @@ impl<TYPE: TypedBitElem> Iterator for TypedBitVecIter<TYPE> {
         let len = self.0.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth(TYPE::PROTO, n)}.map(TYPE::bits_to_val)
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.0.len()
+    }
 }
 
 impl<TYPE: TypedBitElem> DoubleEndedIterator for TypedBitVecIter<TYPE> {
@@ -33,6 +50,11 @@ impl<TYPE: TypedBitElem> DoubleEndedIterator for TypedBitVecIter<TYPE> {
             None => None,
         }
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth_back(TYPE::PROTO, n)}.map(TYPE::bits_to_val)
+    }
 }
 
 impl<TYPE: TypedBitElem> ExactSizeIterator for TypedBitVecIter<TYPE> {
@@ -42,6 +64,26 @@ impl<TYPE: TypedBitElem> ExactSizeIterator for TypedBitVecIter<TYPE> {
     }
 }
 
+impl<TYPE: TypedBitElem> TypedBitVecIter<TYPE> {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecIter::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecIter::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<TYPE::Base> {
+        unsafe {self.0.collect_vec_with(TYPE::PROTO, TYPE::bits_to_val)}
+    }
+}
+
 impl<TYPE: TypedBitElem> Drop for TypedBitVecIter<TYPE>  {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
@@ -65,6 +107,21 @@ impl<'vec, TYPE: TypedBitElem> Iterator for TypedBitVecDrain<'vec, TYPE> {
         let len = self.0.len();
         (len, Some(len))
     }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth(TYPE::PROTO, n)}.map(TYPE::bits_to_val)
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.0.len()
+    }
 }
 
 impl<'vec, TYPE: TypedBitElem> DoubleEndedIterator for TypedBitVecDrain<'vec, TYPE> {
@@ -76,6 +133,11 @@ impl<'vec, TYPE: TypedBitElem> DoubleEndedIterator for TypedBitVecDrain<'vec, TY
             None => None,
         }
     }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        unsafe {self.0.nth_back(TYPE::PROTO, n)}.map(TYPE::bits_to_val)
+    }
 }
 
 impl<'vec, TYPE: TypedBitElem> ExactSizeIterator for TypedBitVecDrain<'vec, TYPE> {
@@ -85,7 +147,96 @@ impl<'vec, TYPE: TypedBitElem> ExactSizeIterator for TypedBitVecDrain<'vec, TYPE
     }
 }
 
+impl<'vec, TYPE: TypedBitElem> TypedBitVecDrain<'vec, TYPE> {
+    /// Skip the next `n` elements in O(1), see [`RawBitVecDrain::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`RawBitVecDrain::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(mut self) -> Vec<TYPE::Base> {
+        unsafe {self.0.collect_vec_with(TYPE::PROTO, TYPE::bits_to_val)}
+    }
+}
+
 impl<'vec, TYPE: TypedBitElem> Drop for TypedBitVecDrain<'vec, TYPE>  {
     #[inline(always)]
     fn drop(&mut self) {/* RawBitVecIter will handle the deallocation */}
-}
\ No newline at end of file
+}
+pub struct TypedBitVecViewIter<'view, TYPE: TypedBitElem>(pub(crate) BitVecViewIter<'view>, pub(crate) PhantomData<TYPE>);
+
+impl<'view, TYPE: TypedBitElem> Iterator for TypedBitVecViewIter<'view, TYPE> {
+    type Item = TYPE::Base;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(TYPE::bits_to_val)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(TYPE::bits_to_val)
+    }
+
+    #[inline(always)]
+    fn last(self) -> Option<Self::Item> {
+        self.0.last().map(TYPE::bits_to_val)
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.0.count()
+    }
+}
+
+impl<'view, TYPE: TypedBitElem> DoubleEndedIterator for TypedBitVecViewIter<'view, TYPE> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(TYPE::bits_to_val)
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth_back(n).map(TYPE::bits_to_val)
+    }
+}
+
+impl<'view, TYPE: TypedBitElem> ExactSizeIterator for TypedBitVecViewIter<'view, TYPE> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'view, TYPE: TypedBitElem> TypedBitVecViewIter<'view, TYPE> {
+    /// Skip the next `n` elements in O(1), see [`BitVecViewIter::advance_by()`]
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_by(n)
+    }
+
+    /// Skip the last `n` elements in O(1), see [`BitVecViewIter::advance_back_by()`]
+    #[inline(always)]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.0.advance_back_by(n)
+    }
+
+    /// Collect every remaining element into a `Vec`, reading them straight into a buffer allocated once with the exact length
+    #[inline]
+    pub fn collect_vec(self) -> Vec<TYPE::Base> {
+        self.0.collect_vec_with(TYPE::bits_to_val)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    BitVecView,
+    TypedBitElem,
+    TypedBitVecViewIter,
+    PhantomData,
+    Range,
+};
+
+/// ## `TypedBitVecView`: "Typed Bitwise Vector View"  
+/// A borrowed, read-only window into a [`TypedBitVec`](crate::TypedBitVec), converting elements to and from
+/// `T::Base` just like the vector it was taken from
+/// 
+/// ### Pros
+/// - Same as [`BitVecView`]
+/// - No [`BitProto`](crate::BitProto) stored, it is always `T::PROTO`
+/// 
+/// ### Cons
+/// - Read-only
+pub struct TypedBitVecView<'view, T: TypedBitElem>(pub(crate) BitVecView<'view>, pub(crate) PhantomData<T>);
+
+impl<'view, T: TypedBitElem> TypedBitVecView<'view, T> {
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Result<T::Base, String> {
+        Ok(T::bits_to_val(self.0.get(idx)?))
+    }
+
+    /// ## Safety
+    /// `idx` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> T::Base {
+        T::bits_to_val(self.0.get_unchecked(idx))
+    }
+
+    #[inline(always)]
+    pub fn slice(&self, idx_range: Range<usize>) -> Result<TypedBitVecView<'view, T>, String> {
+        Ok(TypedBitVecView(self.0.slice(idx_range)?, PhantomData))
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> TypedBitVecViewIter<'view, T> {
+        TypedBitVecViewIter(self.0.iter(), PhantomData)
+    }
+
+    #[inline(always)]
+    pub fn untyped(&self) -> BitVecView<'view> {
+        self.0.clone()
+    }
+}
+
+impl<'view, T: TypedBitElem> Clone for TypedBitVecView<'view, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        TypedBitVecView(self.0.clone(), PhantomData)
+    }
+}
+
+impl<'view, T: TypedBitElem> IntoIterator for TypedBitVecView<'view, T> {
+    type Item = T::Base;
+
+    type IntoIter = TypedBitVecViewIter<'view, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -71,7 +71,7 @@ impl BitUtil {
 
     #[inline(always)]
     pub(crate) const fn smear_neg_bit_left(val: usize, top_bit: usize) -> usize {
-        val | Self::smear_left(top_bit)
+        val | Self::smear_left(val & top_bit)
     }
 
     #[inline(always)]
@@ -192,7 +192,17 @@ impl BitUtil {
         }
     }
 
-    
+    /// Reverse the order of the `lane_bits` wide lanes inside `val`, `lane_bits` must divide `USIZE_BITS`
+    #[inline(always)]
+    pub(crate) const fn reverse_lanes(mut val: usize, lane_bits: usize) -> usize {
+        let mut width = lane_bits;
+        while width < Self::USIZE_BITS {
+            let low_groups_mask = usize::MAX / ((1 << width) + 1);
+            val = ((val >> width) & low_groups_mask) | ((val & low_groups_mask) << width);
+            width <<= 1;
+        }
+        val
+    }
 }
 
 // #[cfg(test)]
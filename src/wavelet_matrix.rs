@@ -0,0 +1,190 @@
+use crate::{
+    BitUtil,
+    CProtoBitVec,
+    ConstGrowth,
+    Range,
+    RankSelect,
+};
+
+/// ## `WaveletMatrix`: "Wavelet Matrix over Packed Symbols"
+/// A read-only index over a sequence of `BIT_WIDTH`-bit symbols, stored as `BIT_WIDTH` levels of 1-bit
+/// [`RankSelect`] vectors (one per symbol bit, most significant first), that answers `access()`, `rank()`, `select()`,
+/// `quantile()` and range frequency queries in O(`BIT_WIDTH`) rank/select operations
+///
+/// Each level holds one bit of every symbol, with the symbols reordered so that those with a `0` in the level
+/// above come first (keeping their relative order)
+///
+/// ### Pros
+/// - About the same size as the [`CProtoBitVec<BIT_WIDTH>`] it was built from, plus the [`RankSelect`] indexes
+/// - Counting and order-statistic queries over any index range without scanning it
+///
+/// ### Cons
+/// - Read-only once built
+/// - `access()` is O(`BIT_WIDTH`) rather than O(1)
+pub struct WaveletMatrix<const BIT_WIDTH: usize> {
+    pub(crate) levels: Vec<RankSelect>,
+    /// The number of zeros in each level
+    pub(crate) zeros: Vec<usize>,
+    pub(crate) len: usize,
+}
+
+impl<const BIT_WIDTH: usize> WaveletMatrix<BIT_WIDTH> {
+    pub fn new<G: ConstGrowth>(symbols: &CProtoBitVec<BIT_WIDTH, G>) -> Result<Self, String> {
+        let len = symbols.len();
+        let mut current = vec![0usize; len];
+        symbols.unpack_into(0..len, &mut current)?;
+        let mut levels = Vec::with_capacity(BIT_WIDTH);
+        let mut zeros = Vec::with_capacity(BIT_WIDTH);
+        for level in 0..BIT_WIDTH {
+            let shift = BIT_WIDTH - 1 - level;
+            let level_bits: Vec<usize> = current.iter().map(|symbol| (symbol >> shift) & 1).collect();
+            let mut bits = CProtoBitVec::<1>::from_elem(0, len)?;
+            bits.pack_from(0, &level_bits)?;
+            let (mut next, ones): (Vec<usize>, Vec<usize>) = current.iter().partition(|symbol| (*symbol >> shift) & 1 == 0);
+            zeros.push(next.len());
+            next.extend(ones);
+            current = next;
+            levels.push(RankSelect::new(bits));
+        }
+        Ok(Self { levels, zeros, len })
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Map position `pos` of `level` to its position in the next level, following the bit `bit`
+    #[inline(always)]
+    fn descend(&self, level: usize, pos: usize, bit: usize) -> usize {
+        let ones = self.levels[level].rank1_unchecked(pos);
+        match bit {
+            0 => pos - ones,
+            _ => self.zeros[level] + ones,
+        }
+    }
+
+    #[inline(always)]
+    fn check_range(&self, range: &Range<usize>, method: &str) -> Result<(), String> {
+        match range.start > range.end || range.end > self.len {
+            true => Err(format!("index out of bounds for {}: (start idx) {} > {} (end idx) OR (end idx) {} > {} (len)", method, range.start, range.end, range.end, self.len)),
+            false => Ok(())
+        }
+    }
+
+    #[inline(always)]
+    fn check_symbol(symbol: usize) -> Result<(), String> {
+        match BIT_WIDTH < BitUtil::USIZE_BITS && symbol >> BIT_WIDTH != 0 {
+            true => Err(format!("symbol cannot be represented in {} bits: {}", BIT_WIDTH, symbol)),
+            false => Ok(())
+        }
+    }
+
+    /// The symbol at `idx`
+    pub fn access(&self, idx: usize) -> Result<usize, String> {
+        if idx >= self.len {
+            return Err(format!("index out of bounds for access: (idx) {} >= {} (len)", idx, self.len));
+        }
+        let mut pos = idx;
+        let mut symbol = 0;
+        for level in 0..BIT_WIDTH {
+            let bit = self.levels[level].get(pos)? as usize;
+            symbol = (symbol << 1) | bit;
+            pos = self.descend(level, pos, bit);
+        }
+        Ok(symbol)
+    }
+
+    /// The number of times `symbol` appears in `0..idx`
+    pub fn rank(&self, symbol: usize, idx: usize) -> Result<usize, String> {
+        Self::check_symbol(symbol)?;
+        self.check_range(&(0..idx), "rank")?;
+        let (start, end) = self.bottom_range(symbol, 0..idx);
+        Ok(end - start)
+    }
+
+    /// The index of the `k`-th occurrence of `symbol` (counting from `0`), or `None` if it appears `k` or fewer times
+    pub fn select(&self, symbol: usize, k: usize) -> Option<usize> {
+        Self::check_symbol(symbol).ok()?;
+        let (start, end) = self.bottom_range(symbol, 0..self.len);
+        if k >= end - start {
+            return None;
+        }
+        let mut pos = start + k;
+        for level in (0..BIT_WIDTH).rev() {
+            pos = match (symbol >> (BIT_WIDTH - 1 - level)) & 1 {
+                0 => self.levels[level].select0(pos)?,
+                _ => self.levels[level].select1(pos - self.zeros[level])?,
+            };
+        }
+        Some(pos)
+    }
+
+    /// The `k`-th smallest symbol (counting from `0`) among the symbols in `range`
+    pub fn quantile(&self, range: Range<usize>, mut k: usize) -> Result<usize, String> {
+        self.check_range(&range, "quantile")?;
+        if k >= range.len() {
+            return Err(format!("rank out of bounds for quantile: (k) {} >= {} (range len)", k, range.len()));
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        let mut symbol = 0;
+        for level in 0..BIT_WIDTH {
+            let zeros_in_range = (end - self.levels[level].rank1_unchecked(end)) - (start - self.levels[level].rank1_unchecked(start));
+            let bit = match k < zeros_in_range {
+                true => 0,
+                false => {
+                    k -= zeros_in_range;
+                    1
+                }
+            };
+            symbol = (symbol << 1) | bit;
+            start = self.descend(level, start, bit);
+            end = self.descend(level, end, bit);
+        }
+        Ok(symbol)
+    }
+
+    /// The number of symbols in `range` that fall in `symbols`
+    pub fn range_freq(&self, range: Range<usize>, symbols: Range<usize>) -> Result<usize, String> {
+        self.check_range(&range, "range_freq")?;
+        if symbols.start >= symbols.end {
+            return Ok(0);
+        }
+        Ok(self.count_less(&range, symbols.end) - self.count_less(&range, symbols.start))
+    }
+
+    /// The number of symbols in `range` that are less than `limit`
+    fn count_less(&self, range: &Range<usize>, limit: usize) -> usize {
+        if Self::check_symbol(limit).is_err() {
+            return range.len();
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        let mut count = 0;
+        for level in 0..BIT_WIDTH {
+            let bit = (limit >> (BIT_WIDTH - 1 - level)) & 1;
+            if bit == 1 {
+                count += (end - self.levels[level].rank1_unchecked(end)) - (start - self.levels[level].rank1_unchecked(start));
+            }
+            start = self.descend(level, start, bit);
+            end = self.descend(level, end, bit);
+        }
+        count
+    }
+
+    /// Follow `symbol` down every level, returning the range of the bottom level holding its occurrences in `range`
+    #[inline]
+    fn bottom_range(&self, symbol: usize, range: Range<usize>) -> (usize, usize) {
+        let (mut start, mut end) = (range.start, range.end);
+        for level in 0..BIT_WIDTH {
+            let bit = (symbol >> (BIT_WIDTH - 1 - level)) & 1;
+            start = self.descend(level, start, bit);
+            end = self.descend(level, end, bit);
+        }
+        (start, end)
+    }
+}